@@ -1,8 +1,11 @@
 use std::{any::Any, collections::BTreeMap};
 
+use bevy::prelude::Entity;
 use bevy_stat_query::{
-    operations::StatOperation::Add, types::StatIntPercentAdditive, Qualifier, QualifierQuery, Stat,
-    StatMap, StatValue,
+    operations::StatOperation::Add,
+    types::{StatInt, StatIntPercentAdditive},
+    Qualifier, QualifierQuery, Querier, Stat, StatInst, StatMap, StatStream, StatValue,
+    StatValuePair,
 };
 use criterion::{criterion_group, criterion_main, Criterion};
 
@@ -36,5 +39,130 @@ pub fn query_many(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, query_many);
+#[derive(Debug, Clone, Copy, Stat)]
+#[stat(value = "StatIntPercentAdditive<i32>")]
+pub enum Many {
+    A,
+    B,
+}
+
+/// A stream doing some work before discovering it does not apply.
+pub struct Buffs<const FILTER: bool> {
+    stat: Many,
+    map: StatMap<u32>,
+}
+
+impl<const FILTER: bool> Buffs<FILTER> {
+    fn new(stat: Many) -> Self {
+        let mut map = StatMap::new();
+        for i in 0..8 {
+            map.insert_base(Qualifier::all_of(1 << i), stat, 1);
+        }
+        Buffs { stat, map }
+    }
+}
+
+impl<const FILTER: bool> StatStream for Buffs<FILTER> {
+    type Qualifier = u32;
+
+    fn stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<u32>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<u32>,
+    ) {
+        self.map.stream_stat(entity, qualifier, stat_value, querier);
+    }
+
+    fn applies_to(&self, stat: StatInst) -> bool {
+        !FILTER || stat.is(&self.stat)
+    }
+}
+
+fn nested<T: StatStream<Qualifier = u32> + Copy>(item: T) -> impl StatStream<Qualifier = u32> {
+    let x2 = (item, item);
+    let x4 = (x2, x2);
+    let x8 = (x4, x4);
+    let x16 = (x8, x8);
+    (x16, x16)
+}
+
+fn query<T: StatStream<Qualifier = u32>>(stream: &T) -> i32 {
+    let mut pair = StatValuePair::new_default(&Many::B);
+    stream.stream_stat(
+        Entity::PLACEHOLDER,
+        &QualifierQuery::Aggregate(255),
+        &mut pair,
+        Querier::noop(),
+    );
+    pair.into_result::<Many>().unwrap().eval()
+}
+
+pub fn stream_filter(c: &mut Criterion) {
+    let unfiltered = Buffs::<false>::new(Many::A);
+    let filtered = Buffs::<true>::new(Many::A);
+    let unfiltered = nested(&unfiltered);
+    let filtered = nested(&filtered);
+
+    c.bench_function("stream_unrelated_unfiltered", |b| {
+        b.iter(|| query(&unfiltered))
+    });
+
+    c.bench_function("stream_unrelated_filtered", |b| b.iter(|| query(&filtered)));
+}
+
+pub fn insert_many(c: &mut Criterion) {
+    let entries: Vec<_> = (0..1024u32)
+        .map(|i| (Qualifier::all_of(i.reverse_bits()), S, Add(1).into_stat()))
+        .collect();
+
+    c.bench_function("stat_map_insert_individually", |b| {
+        b.iter(|| {
+            let mut m = StatMap::<u32>::new();
+            for (qualifier, stat, value) in entries.iter().cloned() {
+                m.insert(qualifier, stat, value);
+            }
+            m
+        })
+    });
+
+    c.bench_function("stat_map_insert_many", |b| {
+        b.iter(|| {
+            let mut m = StatMap::<u32>::new();
+            m.insert_many(entries.iter().cloned());
+            m
+        })
+    });
+}
+
+pub fn join_many(c: &mut Criterion) {
+    let values: Vec<_> = (0..1000)
+        .map(|i| {
+            StatInt::<i32>::from_base(i)
+                .with_mul(if i % 100 == 0 { 2 } else { 1 })
+                .with_max(i)
+        })
+        .collect();
+
+    c.bench_function("stat_int_join", |b| {
+        b.iter(|| {
+            let mut result = StatInt::<i32>::default();
+            for value in values.iter().copied() {
+                result.join(value);
+            }
+            result
+        })
+    });
+
+    c.bench_function("stat_int_join_many", |b| {
+        b.iter(|| {
+            let mut result = StatInt::<i32>::default();
+            result.join_many(values.iter().copied());
+            result
+        })
+    });
+}
+
+criterion_group!(benches, query_many, stream_filter, insert_many, join_many);
 criterion_main!(benches);
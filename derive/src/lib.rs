@@ -1,7 +1,9 @@
 use proc_macro::{Span, TokenStream as TokenStream1};
 use proc_macro_error::{abort, proc_macro_error};
 use quote::{quote, ToTokens};
-use syn::{parse_macro_input, spanned::Spanned, DeriveInput, Fields, LitInt, LitStr, Type};
+use syn::{
+    parse_macro_input, parse_quote, spanned::Spanned, DeriveInput, Fields, LitInt, LitStr, Type,
+};
 
 /// Derive macro for `Stat`.
 ///
@@ -33,10 +35,24 @@ use syn::{parse_macro_input, spanned::Spanned, DeriveInput, Fields, LitInt, LitS
 /// If specified, guarantees no panic even if a bad id
 /// is encountered, this likely will not happen in normal usage,
 /// as id is not used in serialization.
+///
+/// Otherwise a bad id panics, unless `set_lenient_stat_index` is enabled,
+/// in which case the first variant is returned.
+///
+/// Generic structs are supported if all fields are `PhantomData`,
+/// each instantiation is a different stat and must be named with `stat_instance!`.
+///
+/// ```
+/// #[derive(Debug, Clone, Copy, Stat)]
+/// #[stat(value = "StatFloat<T>")]
+/// pub struct MyStat<T: Float>(PhantomData<T>);
+///
+/// stat_instance!(MyStat<f32> = "MyStat<f32>");
+/// ```
 #[proc_macro_error]
 #[proc_macro_derive(Stat, attributes(stat, default))]
 pub fn stat(tokens: TokenStream1) -> TokenStream1 {
-    let input = parse_macro_input!(tokens as DeriveInput);
+    let mut input = parse_macro_input!(tokens as DeriveInput);
     let crate0 = quote! {::bevy_stat_query};
     let name = input.ident;
 
@@ -66,6 +82,54 @@ pub fn stat(tokens: TokenStream1) -> TokenStream1 {
     };
 
     match input.data {
+        syn::Data::Struct(s) if !input.generics.params.is_empty() => {
+            input
+                .generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(Self: #crate0::StatInstance));
+            let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+            let construct = match &s.fields {
+                Fields::Unit => quote! {#name},
+                Fields::Unnamed(fields) => {
+                    let fields = fields
+                        .unnamed
+                        .iter()
+                        .map(|_| quote! {::core::marker::PhantomData});
+                    quote! {#name(#(#fields),*)}
+                }
+                Fields::Named(fields) => {
+                    let fields = fields.named.iter().map(|x| &x.ident);
+                    quote! {#name {#(#fields: ::core::marker::PhantomData),*}}
+                }
+            };
+            quote! {
+                impl #impl_generics #crate0::Stat for #name #ty_generics #where_clause {
+                    type Value = #value;
+
+                    fn name(&self) -> &'static str {
+                        <Self as #crate0::StatInstance>::NAME
+                    }
+
+                    fn vtable() -> &'static #crate0::StatVTable<Self> {
+                        <Self as #crate0::StatInstance>::vtable()
+                    }
+
+                    fn as_index(&self) -> u64 {
+                        0
+                    }
+
+                    fn from_index(_: u64) -> Self {
+                        #construct
+                    }
+
+                    fn values() -> impl IntoIterator<Item = Self> {
+                        [#construct]
+                    }
+                }
+            }
+            .into()
+        }
         syn::Data::Struct(s) => {
             let Fields::Unit = s.fields else {
                 abort!(s.struct_token.span, "Only supports unit structs and enums.");
@@ -98,8 +162,20 @@ pub fn stat(tokens: TokenStream1) -> TokenStream1 {
             .into()
         }
         syn::Data::Enum(e) => {
-            let mut default = quote! {
-                panic!("Invalid value for {}: {}.", stringify!(#name), value)
+            let mut default = match e.variants.first() {
+                Some(first) => {
+                    let first = &first.ident;
+                    quote! {
+                        if #crate0::lenient_stat_index() {
+                            #name::#first
+                        } else {
+                            panic!("Invalid value for {}: {}.", stringify!(#name), value)
+                        }
+                    }
+                }
+                None => quote! {
+                    panic!("Invalid value for {}: {}.", stringify!(#name), value)
+                },
             };
             for v in &e.variants {
                 let variant = &v.ident;
@@ -194,9 +270,10 @@ pub fn attribute(tokens: TokenStream1) -> TokenStream1 {
     let name = input.ident;
     let uniq = quote! {
         {
-            #[used]
-            static THING: ::std::sync::atomic::AtomicU8 = ::std::sync::atomic::AtomicU8::new(0);
-            &THING as *const ::std::sync::atomic::AtomicU8 as usize
+            const TAG: usize = #crate0::attribute_tag(
+                concat!(module_path!(), "::", stringify!(#name))
+            );
+            TAG
         }
     };
     match input.data {
@@ -0,0 +1,239 @@
+use crate::{
+    Qualifier, QualifierFlag, QualifierQuery, Querier, QueryStream, StatDeserializers, StatInst,
+    StatMap, StatStream, StatValuePair,
+};
+use bevy_asset::{io::Reader, Asset, AssetLoader, Assets, Handle, LoadContext};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    system::Res,
+    world::{FromWorld, World},
+};
+use bevy_reflect::TypePath;
+use serde::de::{DeserializeOwned, DeserializeSeed, Deserializer, SeqAccess, Visitor};
+use std::{borrow::Cow, marker::PhantomData};
+
+/// An [`Asset`] containing a table of qualified stat modifiers,
+/// shared by entities through [`StatTable`] handles.
+///
+/// Loaded from `.stats.ron` files by [`StatTableLoader`].
+#[derive(Debug, Clone, Default, Asset, TypePath)]
+pub struct StatTableAsset<Q: QualifierFlag + TypePath> {
+    pub map: StatMap<Q>,
+}
+
+impl<Q: QualifierFlag + TypePath> StatTableAsset<Q> {
+    pub fn new(map: StatMap<Q>) -> Self {
+        StatTableAsset { map }
+    }
+}
+
+impl<Q: QualifierFlag + TypePath> From<StatMap<Q>> for StatTableAsset<Q> {
+    fn from(map: StatMap<Q>) -> Self {
+        StatTableAsset { map }
+    }
+}
+
+/// A [`Component`] that applies the modifiers of a [`StatTableAsset`] to its entity.
+///
+/// Does nothing if the asset is not loaded.
+#[derive(Debug, Clone, Component)]
+pub struct StatTable<Q: QualifierFlag + TypePath>(pub Handle<StatTableAsset<Q>>);
+
+impl<Q: QualifierFlag + TypePath> QueryStream for StatTable<Q> {
+    type Qualifier = Q;
+    type Query = &'static StatTable<Q>;
+    type Context = Res<'static, Assets<StatTableAsset<Q>>>;
+
+    fn stream_stat(
+        query: &StatTable<Q>,
+        context: &Res<Assets<StatTableAsset<Q>>>,
+        entity: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Q>,
+    ) {
+        if let Some(table) = context.get(query.0.id()) {
+            table
+                .map
+                .stream_stat(entity, qualifier, stat_value, querier);
+        }
+    }
+}
+
+/// An [`AssetLoader`] for [`StatTableAsset`]s in `.stats.ron` files.
+///
+/// The file is a list of `(qualifier, stat name, operation)` entries,
+/// operations on the same stat and qualifier are applied in order.
+///
+/// ```ron
+/// [
+///     ((all_of: 0, any_of: 0), "Damage", Base(4.0)),
+///     ((all_of: 0, any_of: 0), "Damage", Mul(2.0)),
+///     ((all_of: 1, any_of: 0), "Defense", Add(3.0)),
+/// ]
+/// ```
+///
+/// Stat names are resolved through the [`StatDeserializers`] of the world the loader is created in,
+/// so stats must be registered before the loader, e.g. `app.register_stat::<S>()`
+/// before `app.init_asset_loader::<StatTableLoader<Q>>()`.
+pub struct StatTableLoader<Q: QualifierFlag> {
+    deserializers: StatDeserializers,
+    p: PhantomData<Q>,
+}
+
+impl<Q: QualifierFlag> StatTableLoader<Q> {
+    pub fn new(deserializers: StatDeserializers) -> Self {
+        StatTableLoader {
+            deserializers,
+            p: PhantomData,
+        }
+    }
+}
+
+impl<Q: QualifierFlag> FromWorld for StatTableLoader<Q> {
+    fn from_world(world: &mut World) -> Self {
+        StatTableLoader::new(
+            world
+                .get_resource::<StatDeserializers>()
+                .cloned()
+                .unwrap_or_default(),
+        )
+    }
+}
+
+/// Error produced by [`StatTableLoader`].
+#[derive(Debug, thiserror::Error)]
+pub enum StatTableLoaderError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Ron(#[from] ron::error::SpannedError),
+}
+
+impl<Q: QualifierFlag + TypePath + DeserializeOwned> AssetLoader for StatTableLoader<Q> {
+    type Asset = StatTableAsset<Q>;
+    type Settings = ();
+    type Error = StatTableLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _: &(),
+        _: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let mut map = StatMap::new();
+        let mut deserializer = ron::Deserializer::from_bytes(&bytes)?;
+        TableSeed {
+            deserializers: &self.deserializers,
+            map: &mut map,
+        }
+        .deserialize(&mut deserializer)
+        .map_err(|e| deserializer.span_error(e))?;
+        deserializer.end().map_err(|e| deserializer.span_error(e))?;
+        Ok(StatTableAsset { map })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["stats.ron"]
+    }
+}
+
+/// Deserializes a list of `(qualifier, stat name, operation)` into a [`StatMap`].
+struct TableSeed<'t, Q: QualifierFlag> {
+    deserializers: &'t StatDeserializers,
+    map: &'t mut StatMap<Q>,
+}
+
+impl<'de, Q: QualifierFlag + DeserializeOwned> DeserializeSeed<'de> for TableSeed<'_, Q> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, Q: QualifierFlag + DeserializeOwned> Visitor<'de> for TableSeed<'_, Q> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a list of (qualifier, stat, operation)")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        while seq
+            .next_element_seed(EntrySeed {
+                deserializers: self.deserializers,
+                map: self.map,
+            })?
+            .is_some()
+        {}
+        Ok(())
+    }
+}
+
+/// Deserializes a `(qualifier, stat name, operation)` and applies it to a [`StatMap`].
+struct EntrySeed<'t, Q: QualifierFlag> {
+    deserializers: &'t StatDeserializers,
+    map: &'t mut StatMap<Q>,
+}
+
+impl<'de, Q: QualifierFlag + DeserializeOwned> DeserializeSeed<'de> for EntrySeed<'_, Q> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_tuple(3, self)
+    }
+}
+
+impl<'de, Q: QualifierFlag + DeserializeOwned> Visitor<'de> for EntrySeed<'_, Q> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("(qualifier, stat, operation)")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let Some(qualifier) = seq.next_element::<Qualifier<Q>>()? else {
+            return Err(serde::de::Error::custom("Expected qualifier."));
+        };
+        let Some(name) = seq.next_element::<Cow<str>>()? else {
+            return Err(serde::de::Error::custom("Expected stat name."));
+        };
+        let Some(stat) = self.deserializers.get(&name) else {
+            return Err(serde::de::Error::custom(format!(
+                "Unable to parse Stat \"{name}\"."
+            )));
+        };
+        let seed = OperationSeed {
+            qualifier,
+            stat,
+            map: self.map,
+        };
+        if seq.next_element_seed(seed)?.is_none() {
+            return Err(serde::de::Error::custom("Expected stat operation."));
+        }
+        Ok(())
+    }
+}
+
+/// Deserializes a [`StatOperation`](crate::operations::StatOperation) of a resolved stat
+/// and applies it to a [`StatMap`].
+struct OperationSeed<'t, Q: QualifierFlag> {
+    qualifier: Qualifier<Q>,
+    stat: StatInst,
+    map: &'t mut StatMap<Q>,
+}
+
+impl<'de, Q: QualifierFlag> DeserializeSeed<'de> for OperationSeed<'_, Q> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        let deserializer = &mut <dyn erased_serde::Deserializer>::erase(deserializer);
+        self.map
+            .modify_deserialize(self.qualifier, self.stat, deserializer)
+            .map_err(serde::de::Error::custom)
+    }
+}
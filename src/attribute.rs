@@ -1,9 +1,18 @@
+use serde::{Deserialize, Serialize};
+
 /// Represents either a string or a typed enum.
+///
+/// Tags generated by `#[derive(Attribute)]` are hashes of the type's path,
+/// so they are stable across runs and can be serialized.
 #[repr(C)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum Attribute<'t> {
+    #[serde(borrow)]
     String(&'t str),
-    Enum { tag: usize, index: u64 },
+    Enum {
+        tag: usize,
+        index: u64,
+    },
 }
 
 impl<'t> From<&'t str> for Attribute<'t> {
@@ -11,3 +20,18 @@ impl<'t> From<&'t str> for Attribute<'t> {
         Attribute::String(val)
     }
 }
+
+/// Stable tag of an attribute type from its path, used by `#[derive(Attribute)]`.
+///
+/// This is the 64 bit FNV-1a hash of `name`.
+pub const fn attribute_tag(name: &str) -> usize {
+    let bytes = name.as_bytes();
+    let mut hash = 0xcbf29ce484222325u64;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+        i += 1;
+    }
+    hash as usize
+}
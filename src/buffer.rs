@@ -1,3 +1,4 @@
+use bevy_ecs::ptr::PtrMut;
 use std::{
     any::type_name,
     cell::UnsafeCell,
@@ -48,6 +49,11 @@ impl Buffer {
         unsafe { (self.0.get_mut().as_ptr() as *mut T).as_mut() }.unwrap()
     }
 
+    /// Obtain a type erased pointer to the item.
+    pub(crate) fn as_ptr_mut(&mut self) -> PtrMut<'_> {
+        PtrMut::from(self.0.get_mut())
+    }
+
     /// Convert to a concrete item.
     pub(crate) unsafe fn into<T: Send + Sync>(mut self) -> T {
         validate::<T>();
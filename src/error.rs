@@ -0,0 +1,18 @@
+use bevy_ecs::entity::Entity;
+use std::borrow::Cow;
+
+/// A recoverable error produced by [`StatStream::try_stream_stat`](crate::StatStream::try_stream_stat)
+/// or [`StatStream::try_stream_relation`](crate::StatStream::try_stream_relation).
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum StatError {
+    #[error("Failed to query stat {stat} on {entity}.")]
+    QueryFailed { entity: Entity, stat: &'static str },
+    #[error("Failed to query relation stat {stat} from {from} to {to}.")]
+    RelationFailed {
+        from: Entity,
+        to: Entity,
+        stat: &'static str,
+    },
+    #[error("{0}")]
+    Custom(Cow<'static, str>),
+}
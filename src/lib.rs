@@ -11,31 +11,52 @@ This is almost certainly a bug since we do not provide a type erased api.";
 pub use bevy_app::{App, Plugin};
 
 mod num_traits;
-pub use num_traits::{Flags, Float, Fraction, Int};
+pub use num_traits::{Flags, Float, Fraction, Int, Number};
+
+/// Serde helpers for [`Fraction`].
+pub mod fraction {
+    pub use crate::num_traits::readable;
+}
 mod stream;
 pub use stream::*;
+mod stream_ext;
+pub use stream_ext::{Qualified, Scaled, StatStreamExt, WhenAttr};
 mod querier;
 pub use querier::*;
 mod qualifier;
 pub mod types;
-pub use qualifier::{Qualifier, QualifierFlag, QualifierQuery};
+pub use qualifier::{Qualifier, QualifierFlag, QualifierPair, QualifierQuery};
 mod stat;
 #[cfg(feature = "derive")]
 pub use bevy_stat_query_derive::{Attribute, Stat};
+#[cfg(feature = "json")]
+pub use stat::StatDescription;
 pub(crate) use stat::StatExt;
-pub(crate) use stat::StatInst;
-pub use stat::{Stat, StatVTable, StatValuePair};
+pub use stat::{
+    lenient_stat_index, set_lenient_stat_index, CachedEval, Stat, StatInst, StatInstance,
+    StatVTable, StatValuePair,
+};
 pub mod operations;
 pub use operations::StatValue;
 mod plugin;
 pub use plugin::{GlobalStatDefaults, GlobalStatRelations, StatDeserializers, StatExtension};
 mod stat_map;
-pub use stat_map::StatMap;
+pub use stat_map::{
+    ReflectStatEntry, ReflectStatMap, StatEntry, StatMap, StatMapCheckpoint, StatMapRecorder,
+};
+#[cfg(feature = "asset")]
+mod asset;
+#[cfg(feature = "asset")]
+pub use asset::{StatTable, StatTableAsset, StatTableLoader, StatTableLoaderError};
 mod buffer;
 pub mod rounding;
 use std::fmt::Debug;
+mod visitor;
+pub use visitor::{EvalValue, StatVisitor};
 mod attribute;
-pub use attribute::Attribute;
+mod error;
+pub use attribute::{attribute_tag, Attribute};
+pub use error::StatError;
 
 mod sealed {
     pub trait Sealed {}
@@ -67,6 +88,33 @@ macro_rules! vtable {
     }};
 }
 
+/// Implement [`StatInstance`] for one instantiation of a generic [`Stat`].
+/// ```
+/// stat_instance!(Type<i32> = "Type<i32>");
+/// ```
+/// Equivalent to
+/// ```
+/// impl StatInstance for Type<i32> {
+///     const NAME: &'static str = "Type<i32>";
+///
+///     fn vtable() -> &'static StatVTable<Self> {
+///         vtable!(Type<i32>)
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! stat_instance {
+    ($ty: ty = $name: expr) => {
+        impl $crate::StatInstance for $ty {
+            const NAME: &'static str = $name;
+
+            fn vtable() -> &'static $crate::StatVTable<Self> {
+                $crate::vtable!($ty)
+            }
+        }
+    };
+}
+
 /// Downcast [`StatValuePair`] to a concrete pair of stat and value.
 ///
 /// # Syntax
@@ -112,6 +160,69 @@ macro_rules! match_stat {
     ($stat_value: expr => {}) => {()};
 }
 
+/// Define a struct whose fields are evaluated from [`Stat`]s.
+///
+/// Generates `from_stat_map`, which evaluates each field from a [`StatMap`],
+/// and `from_querier`, which returns `None` if any stat is not found.
+///
+/// # Syntax
+///
+/// ```
+/// # /*
+/// stat_struct! {
+///     #[derive(Debug)]
+///     pub struct CombatStats {
+///         // field: evaluated type = stat
+///         pub attack: i32 = Combat::Attack,
+///         pub defense: i32 = Combat::Defense,
+///     }
+/// }
+/// let stats = CombatStats::from_stat_map(&map, &QualifierQuery::none());
+/// # */
+/// ```
+#[macro_export]
+macro_rules! stat_struct {
+    (
+        $(#[$($attr: tt)*])*
+        $vis: vis struct $name: ident {
+            $($(#[$($field_attr: tt)*])* $field_vis: vis $field: ident: $ty: ty = $stat: expr),* $(,)?
+        }
+    ) => {
+        $(#[$($attr)*])*
+        $vis struct $name {
+            $($(#[$($field_attr)*])* $field_vis $field: $ty),*
+        }
+
+        impl $name {
+            /// Evaluate all fields from a `StatMap`.
+            #[allow(unused)]
+            pub fn from_stat_map<Q: $crate::QualifierFlag>(
+                map: &$crate::StatMap<Q>,
+                qualifier: &$crate::QualifierQuery<Q>,
+            ) -> Self {
+                Self {
+                    $($field: map.eval_stat(qualifier, &$stat)),*
+                }
+            }
+
+            /// Evaluate all fields on an entity, returns `None` if any stat is not found.
+            #[allow(unused)]
+            pub fn from_querier<Q: $crate::QualifierFlag>(
+                querier: $crate::Querier<Q>,
+                entity: $crate::__Entity,
+                qualifier: &$crate::QualifierQuery<Q>,
+            ) -> Option<Self> {
+                Some(Self {
+                    $($field: querier.eval_stat(entity, qualifier, &$stat)?),*
+                })
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+pub use bevy_ecs::entity::Entity as __Entity;
+
 use buffer::{validate, Buffer};
 
 #[cfg(test)]
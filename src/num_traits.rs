@@ -1,12 +1,13 @@
 use crate::Shareable;
-use bevy_reflect::TypePath;
-use num_rational::Ratio;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect, ReflectDeserialize, ReflectSerialize};
+use num_rational::{ParseRatioError, Ratio};
 use num_traits::AsPrimitive;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::{
-    fmt::Debug,
+    fmt::{Debug, Display},
     num::{Saturating, Wrapping},
     ops::*,
+    str::FromStr,
 };
 
 pub trait NumInteger: num_integer::Integer + num_traits::NumAssign {}
@@ -81,11 +82,63 @@ pub trait Int: NumOps + PartialOrd + Default + Copy + Shareable {
     const MIN_VALUE: Self;
     const MAX_VALUE: Self;
 
+    /// Convert from an `i64`, clamping to the range of `Self`.
     fn from_i64(value: i64) -> Self;
 
+    /// Convert from an `i64`, returns `None` if out of the range of `Self`.
+    ///
+    /// By default checks if [`Int::from_i64`] round trips.
+    fn try_from_i64(value: i64) -> Option<Self> {
+        let result = Self::from_i64(value);
+        (result.to_i64() == value).then_some(result)
+    }
+
+    /// Convert to an `i64`, clamping to the range of `i64`.
+    ///
+    /// By default binary searches with [`Int::from_i64`], implementors should override this.
+    fn to_i64(self) -> i64 {
+        // The first `i64` that satisfies `predicate`, or `i64::MAX`.
+        let first = |predicate: &dyn Fn(Self) -> bool| {
+            let (mut lo, mut hi) = (i64::MIN, i64::MAX);
+            while lo < hi {
+                let mid = ((lo as i128 + hi as i128) >> 1) as i64;
+                if predicate(Self::from_i64(mid)) {
+                    hi = mid;
+                } else {
+                    lo = mid + 1;
+                }
+            }
+            lo
+        };
+        match first(&|x| x >= self) {
+            // `from_i64` clamps, so `Self::MIN_VALUE` is reached before its own value.
+            i64::MIN => first(&|x| x > self).saturating_sub(1),
+            value => value,
+        }
+    }
+
     fn min(self, other: Self) -> Self;
     fn max(self, other: Self) -> Self;
 
+    /// Greatest common divisor, always non-negative, `gcd(0, 0)` is `0`.
+    ///
+    /// By default computed on [`Int::PrimInt`].
+    fn gcd(self, other: Self) -> Self {
+        let (a, b) = (self.into_fraction(), other.into_fraction());
+        let gcd = num_integer::Integer::gcd(&a.to_integer(), &b.to_integer());
+        Self::from_fraction(Fraction::new_raw(gcd, Int::ONE))
+    }
+
+    /// Least common multiple, always non-negative, `0` if either side is `0`.
+    ///
+    /// Computed by dividing by the [`Int::gcd`] before multiplying to limit overflow.
+    /// By default computed on [`Int::PrimInt`].
+    fn lcm(self, other: Self) -> Self {
+        let (a, b) = (self.into_fraction(), other.into_fraction());
+        let lcm = num_integer::Integer::lcm(&a.to_integer(), &b.to_integer());
+        Self::from_fraction(Fraction::new_raw(lcm, Int::ONE))
+    }
+
     type PrimInt: Int + NumInteger + Clone + Shareable;
 
     fn into_fraction(self) -> Fraction<Self::PrimInt>;
@@ -93,8 +146,29 @@ pub trait Int: NumOps + PartialOrd + Default + Copy + Shareable {
     fn from_fraction(frac: Fraction<Self::PrimInt>) -> Self;
 }
 
+/// Trait for an [`Int`] or a [`Float`].
+///
+/// Constants are named differently from [`Int`] and [`Float`] to avoid ambiguity.
+pub trait Number: PartialOrd + Default + Copy + Shareable {
+    /// Same as `MIN_VALUE`.
+    const LOWEST: Self;
+    /// Same as `MAX_VALUE`.
+    const HIGHEST: Self;
+}
+
+macro_rules! impl_number {
+    ($trait: ident: $($ty: ty),* $(,)?) => {
+        $(impl Number for $ty {
+            const LOWEST: Self = <$ty as $trait>::MIN_VALUE;
+            const HIGHEST: Self = <$ty as $trait>::MAX_VALUE;
+        })*
+    };
+}
+
 macro_rules! impl_int {
     ($($ty: ty),* $(,)?) => {
+        impl_number!(Int: $($ty),*);
+
         $(impl Int for $ty {
             const ZERO: Self = 0;
             const ONE: Self = 1;
@@ -103,7 +177,15 @@ macro_rules! impl_int {
             const MAX_VALUE: Self = <$ty>::MAX;
 
             fn from_i64(value: i64) -> Self{
-                value.clamp(Self::MIN as i64, Self::MAX as i64) as Self
+                Self::try_from_i64(value).unwrap_or(if value < 0 { Self::MIN } else { Self::MAX })
+            }
+
+            fn try_from_i64(value: i64) -> Option<Self> {
+                <$ty>::try_from(value).ok()
+            }
+
+            fn to_i64(self) -> i64 {
+                i64::try_from(self).unwrap_or(if self < Self::ZERO { i64::MIN } else { i64::MAX })
             }
 
             fn min(self, other: Self) -> Self {
@@ -114,6 +196,14 @@ macro_rules! impl_int {
                 Ord::max(self, other)
             }
 
+            fn gcd(self, other: Self) -> Self {
+                num_integer::Integer::gcd(&self, &other)
+            }
+
+            fn lcm(self, other: Self) -> Self {
+                num_integer::Integer::lcm(&self, &other)
+            }
+
             type PrimInt = $ty;
 
             fn into_fraction(self) -> Fraction<Self::PrimInt> {
@@ -135,6 +225,8 @@ impl_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize,);
 
 macro_rules! impl_int_newtype {
     ($($base: ident {$($ty: ty),* $(,)?}),* $(,)?) => {
+        $($(impl_number!(Int: $base<$ty>);)*)*
+
         $($(impl Int for $base<$ty> {
             const ZERO: Self = Self(0);
             const ONE: Self = Self(1);
@@ -143,7 +235,15 @@ macro_rules! impl_int_newtype {
             const MAX_VALUE: Self = Self(<$ty>::MAX);
 
             fn from_i64(value: i64) -> Self{
-                Self(value.clamp(<$ty>::MIN as i64, <$ty>::MAX as i64) as $ty)
+                Self::try_from_i64(value).unwrap_or(if value < 0 { Self::MIN_VALUE } else { Self::MAX_VALUE })
+            }
+
+            fn try_from_i64(value: i64) -> Option<Self> {
+                <$ty>::try_from(value).ok().map(Self)
+            }
+
+            fn to_i64(self) -> i64 {
+                self.0.to_i64()
             }
 
             fn min(self, other: Self) -> Self {
@@ -154,6 +254,14 @@ macro_rules! impl_int_newtype {
                 Ord::max(self, other)
             }
 
+            fn gcd(self, other: Self) -> Self {
+                Self(num_integer::Integer::gcd(&self.0, &other.0))
+            }
+
+            fn lcm(self, other: Self) -> Self {
+                Self(num_integer::Integer::lcm(&self.0, &other.0))
+            }
+
             type PrimInt = $ty;
 
             fn into_fraction(self) -> Fraction<Self::PrimInt> {
@@ -203,13 +311,78 @@ impl_int_newtype!(
 );
 
 /// Trait for a floating point number or a [`Fraction`].
-pub trait Float: NumOps + PartialOrd + Default + Copy + Shareable {
+pub trait Float:
+    NumOps + Div<Self, Output = Self> + PartialOrd + Default + Copy + Shareable
+{
     const ZERO: Self;
     const ONE: Self;
 
     const MIN_VALUE: Self;
     const MAX_VALUE: Self;
 
+    /// Convert from an `i64`, may lose precision.
+    ///
+    /// By default summed from powers of two, implementors should override this.
+    fn from_i64(value: i64) -> Self {
+        let (mut result, mut power) = (Self::ZERO, Self::ONE);
+        let mut rest = value.unsigned_abs();
+        while rest != 0 {
+            if rest & 1 == 1 {
+                result += power;
+            }
+            rest >>= 1;
+            if rest != 0 {
+                power = power + power;
+            }
+        }
+        if value < 0 {
+            Self::ZERO - result
+        } else {
+            result
+        }
+    }
+    /// Convert from `numer / denom`, may lose precision.
+    ///
+    /// By default divides [`Float::from_i64`] of both.
+    fn from_ratio(numer: i64, denom: i64) -> Self {
+        Self::from_i64(numer) / Self::from_i64(denom)
+    }
+
+    /// Convert to an `f64`, may lose precision.
+    ///
+    /// By default computed from the binary expansion of `self`, implementors should override this.
+    fn to_f64(self) -> f64 {
+        let (sign, mut rest) = if self < Self::ZERO {
+            (-1.0, Self::ZERO - self)
+        } else {
+            (1.0, self)
+        };
+        let mut powers = vec![(Self::ONE, 1.0)];
+        while let Some(&(power, value)) = powers.last() {
+            if value >= f64::MAX / 2.0 || power > rest - power {
+                break;
+            }
+            powers.push((power + power, value * 2.0));
+        }
+        let mut result = 0.0;
+        for (power, value) in powers.into_iter().rev() {
+            if rest >= power {
+                rest = rest - power;
+                result += value;
+            }
+        }
+        let mut bit = 0.5;
+        for _ in 0..f64::MANTISSA_DIGITS {
+            rest = rest + rest;
+            if rest >= Self::ONE {
+                rest = rest - Self::ONE;
+                result += bit;
+            }
+            bit /= 2.0;
+        }
+        sign * result
+    }
+
     fn min(self, other: Self) -> Self;
     fn max(self, other: Self) -> Self;
 
@@ -225,6 +398,18 @@ impl Float for f32 {
     const MIN_VALUE: Self = f32::MIN;
     const MAX_VALUE: Self = f32::MAX;
 
+    fn from_i64(value: i64) -> Self {
+        value as f32
+    }
+
+    fn from_ratio(numer: i64, denom: i64) -> Self {
+        numer as f32 / denom as f32
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
     fn min(self, other: Self) -> Self {
         self.min(other)
     }
@@ -256,6 +441,18 @@ impl Float for f64 {
     const MIN_VALUE: Self = f64::MIN;
     const MAX_VALUE: Self = f64::MAX;
 
+    fn from_i64(value: i64) -> Self {
+        value as f64
+    }
+
+    fn from_ratio(numer: i64, denom: i64) -> Self {
+        numer as f64 / denom as f64
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
     fn min(self, other: Self) -> Self {
         self.min(other)
     }
@@ -285,8 +482,10 @@ impl Float for f64 {
 ///
 /// Newtype of [`num_rational::Ratio`].
 #[derive(
-    Debug, Clone, Copy, Default, TypePath, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+    Debug, Clone, Copy, Default, Reflect, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
 )]
+#[reflect(opaque, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[reflect(where I: Serialize + DeserializeOwned)]
 #[repr(transparent)]
 #[serde(transparent)]
 pub struct Fraction<I: Int + NumInteger>(num_rational::Ratio<I>);
@@ -317,6 +516,60 @@ impl<I: Int + NumInteger> Fraction<I> {
     pub fn into_inner(self) -> num_rational::Ratio<I> {
         self.0
     }
+
+    /// Returns the reciprocal, e.g. to revert a multiplier.
+    ///
+    /// # Panics
+    ///
+    /// If the fraction is zero.
+    pub fn recip(self) -> Self {
+        Self(self.0.recip())
+    }
+
+    /// Returns the absolute value, with both numerator and denominator non-negative.
+    pub fn abs(self) -> Self
+    where
+        I: Neg<Output = I>,
+    {
+        let abs = |x: I| if x < I::ZERO { -x } else { x };
+        Self::new_raw(abs(*self.0.numer()), abs(*self.0.denom()))
+    }
+
+    /// Restrict the value to `lo..=hi`.
+    ///
+    /// # Panics
+    ///
+    /// If `lo > hi`.
+    pub fn clamp(self, lo: Self, hi: Self) -> Self {
+        assert!(lo <= hi, "Fraction::clamp called with lo > hi.");
+        if self < lo {
+            lo
+        } else if self > hi {
+            hi
+        } else {
+            self
+        }
+    }
+
+    /// Convert to a [`f64`].
+    ///
+    /// A zero denominator evaluates to `INFINITY`, `NEG_INFINITY` or `NAN` depending on the numerator.
+    pub fn to_f64(&self) -> f64
+    where
+        I: AsPrimitive<f64>,
+    {
+        self.0.numer().as_() / self.0.denom().as_()
+    }
+
+    /// Convert to a [`f32`].
+    ///
+    /// A zero denominator evaluates to `INFINITY`, `NEG_INFINITY` or `NAN` depending on the numerator.
+    pub fn to_f32(&self) -> f32
+    where
+        I: AsPrimitive<f32>,
+    {
+        self.0.numer().as_() / self.0.denom().as_()
+    }
 }
 
 macro_rules! impl_as {
@@ -377,12 +630,88 @@ impl_ops!(Mul, mul, *, MulAssign, mul_assign, *=);
 impl_ops!(Div, div, /, DivAssign, div_assign, /=);
 impl_ops!(Rem, rem, %, RemAssign, rem_assign, %=);
 
+impl<I: Int + NumInteger + Neg<Output = I>> Neg for Fraction<I> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+/// Formats as `numer/denom`, or `numer` if the denominator is `1`.
+impl<I: Int + NumInteger + Display> Display for Fraction<I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+/// Parses `numer/denom` or `numer`.
+impl<I: Int + NumInteger + FromStr> FromStr for Fraction<I> {
+    type Err = ParseRatioError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ratio::from_str(s).map(Self)
+    }
+}
+
+/// Serialize a [`Fraction`] as a human readable string like `"3/4"`.
+///
+/// ```
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(with = "bevy_stat_query::fraction::readable")]
+///     ratio: Fraction<i32>,
+/// }
+/// ```
+pub mod readable {
+    use super::{Fraction, Int, NumInteger};
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+    use std::{fmt::Display, str::FromStr};
+
+    pub fn serialize<I, S>(value: &Fraction<I>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        I: Int + NumInteger + Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, I, D>(deserializer: D) -> Result<Fraction<I>, D::Error>
+    where
+        I: Int + NumInteger + FromStr,
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse()
+            .map_err(|_| D::Error::custom(format!("Invalid fraction \"{s}\".")))
+    }
+}
+
+impl_number!(Float: f32, f64);
+
+impl<I: Int + NumInteger + Clone> Number for Fraction<I> {
+    const LOWEST: Self = <Self as Float>::MIN_VALUE;
+    const HIGHEST: Self = <Self as Float>::MAX_VALUE;
+}
+
 impl<I: Int + NumInteger + Clone> Float for Fraction<I> {
     const ZERO: Self = Fraction::new_raw(I::ZERO, I::ONE);
     const ONE: Self = Fraction::new_raw(I::ONE, I::ONE);
     const MIN_VALUE: Self = Fraction::new_raw(I::MIN_VALUE, I::ONE);
     const MAX_VALUE: Self = Fraction::new_raw(I::MAX_VALUE, I::ONE);
 
+    fn from_i64(value: i64) -> Self {
+        Fraction::new_raw(I::from_i64(value), I::ONE)
+    }
+
+    fn from_ratio(numer: i64, denom: i64) -> Self {
+        Fraction::new(I::from_i64(numer), I::from_i64(denom))
+    }
+
+    fn to_f64(self) -> f64 {
+        self.0.numer().to_i64() as f64 / self.0.denom().to_i64() as f64
+    }
+
     fn min(self, other: Self) -> Self {
         Ord::min(self, other)
     }
@@ -407,3 +736,19 @@ impl<I: Int + NumInteger + Clone> Float for Fraction<I> {
         Self(num_rational::Ratio::round(&self.0))
     }
 }
+
+/// Interpolate `a * (1 - weight) + b * weight` on [`Int`]s.
+pub(crate) fn lerp_int<T: Int>(a: T, b: T, weight: Fraction<i32>) -> T {
+    let weight = Fraction::new(
+        T::PrimInt::from_i64(*weight.numer() as i64),
+        T::PrimInt::from_i64(*weight.denom() as i64),
+    );
+    let rest = <Fraction<T::PrimInt> as Float>::ONE - weight;
+    T::from_fraction(a.into_fraction() * rest + b.into_fraction() * weight)
+}
+
+/// Interpolate `a * (1 - weight) + b * weight` on [`Float`]s.
+pub(crate) fn lerp_float<T: Float>(a: T, b: T, weight: Fraction<i32>) -> T {
+    let (numer, denom) = (*weight.numer() as i64, *weight.denom() as i64);
+    a * T::from_ratio(denom - numer, denom) + b * T::from_ratio(numer, denom)
+}
@@ -1,5 +1,6 @@
 use bevy_reflect::TypePath;
 use serde::{Deserialize, Serialize};
+use std::ops::{Neg, Sub};
 
 /// An single step unordered operation on a [`StatValue`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
@@ -14,7 +15,7 @@ pub enum StatOperation<S: StatValue> {
 
 pub use StatOperation::*;
 
-use crate::Shareable;
+use crate::{EvalValue, Fraction, Shareable};
 
 impl<S: StatValue> StatOperation<S> {
     pub fn write_to(&self, to: &mut S) {
@@ -28,6 +29,18 @@ impl<S: StatValue> StatOperation<S> {
         }
     }
 
+    /// Same as [`StatOperation::write_to`], returns true if the value changed.
+    ///
+    /// Useful for dirty tracking, e.g. `Add(0)` returns false.
+    pub fn write_to_changed(&self, to: &mut S) -> bool
+    where
+        S: PartialEq,
+    {
+        let before = to.clone();
+        self.write_to(to);
+        *to != before
+    }
+
     pub fn into_stat(self) -> S {
         let mut v = S::default();
         self.write_to(&mut v);
@@ -35,10 +48,69 @@ impl<S: StatValue> StatOperation<S> {
     }
 }
 
+/// Describes how operations of the same kind merge, used in [`StatValue::simplify_ops`].
+pub(crate) struct OpMerger<S: StatValue> {
+    pub add: Option<fn(S::Add, S::Add) -> S::Add>,
+    pub mul: Option<fn(S::Mul, S::Mul) -> S::Mul>,
+    pub or: Option<fn(S::Bit, S::Bit) -> S::Bit>,
+    pub min: Option<fn(S::Bounds, S::Bounds) -> S::Bounds>,
+    pub max: Option<fn(S::Bounds, S::Bounds) -> S::Bounds>,
+}
+
+impl<S: StatValue> OpMerger<S> {
+    pub const NONE: Self = OpMerger {
+        add: None,
+        mul: None,
+        or: None,
+        min: None,
+        max: None,
+    };
+
+    pub fn simplify(&self, mut ops: Vec<StatOperation<S>>) -> Vec<StatOperation<S>> {
+        fn merge<T: Clone>(f: Option<fn(T, T) -> T>, prev: &mut T, next: &T) -> bool {
+            match f {
+                Some(f) => {
+                    *prev = f(prev.clone(), next.clone());
+                    true
+                }
+                None => false,
+            }
+        }
+        // `Base` overwrites all previous operations.
+        let start = ops
+            .iter()
+            .rposition(|op| matches!(op, StatOperation::Base(_)))
+            .unwrap_or(0);
+        let mut result: Vec<StatOperation<S>> = Vec::new();
+        for op in ops.drain(start..) {
+            let merged = result.iter_mut().any(|prev| match (prev, &op) {
+                (Add(a), Add(b)) => merge(self.add, a, b),
+                (Mul(a), Mul(b)) => merge(self.mul, a, b),
+                (Or(a), Or(b)) => merge(self.or, a, b),
+                (Min(a), Min(b)) => merge(self.min, a, b),
+                (Max(a), Max(b)) => merge(self.max, a, b),
+                _ => false,
+            });
+            if !merged {
+                result.push(op);
+            }
+        }
+        result
+    }
+}
+
 /// A never type indicating an operation is not supported.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, TypePath, Serialize, Deserialize)]
 pub enum Unsupported {}
 
+impl Neg for Unsupported {
+    type Output = Unsupported;
+
+    fn neg(self) -> Self::Output {
+        match self {}
+    }
+}
+
 /// Defines unordered operations on a stat's value.
 #[allow(unused_variables)]
 pub trait StatValue: Shareable + Default {
@@ -50,8 +122,34 @@ pub trait StatValue: Shareable + Default {
         self.join(other.clone())
     }
 
+    /// Join multiple values, types may override this with a tighter fold.
+    fn join_many(&mut self, others: impl Iterator<Item = Self>) {
+        for other in others {
+            self.join(other)
+        }
+    }
+
     fn eval(&self) -> Self::Out;
 
+    /// Consuming version of [`StatValue::eval`], types may override this
+    /// to move internal data instead of cloning.
+    ///
+    /// Used when the accumulated value is discarded after evaluation, e.g. in `eval_stat`.
+    fn finish(self) -> Self::Out {
+        self.eval()
+    }
+
+    /// Difference between the evaluated forms of `after` and `before`.
+    ///
+    /// Useful for attributing the contribution of a single source,
+    /// e.g. `+10` in a tooltip when a `+5` modifier is applied to a doubled stat.
+    fn eval_delta(before: &Self, after: &Self) -> Self::Out
+    where
+        Self::Out: Sub<Output = Self::Out>,
+    {
+        after.eval() - before.eval()
+    }
+
     type Add: Shareable;
     type Mul: Shareable;
     type Bit: Shareable;
@@ -60,11 +158,77 @@ pub trait StatValue: Shareable + Default {
 
     fn add(&mut self, other: Self::Add) {}
     fn mul(&mut self, other: Self::Mul) {}
+
+    /// Multiply the additive part of the value by `factor`,
+    /// which has the same units as [`StatValue::mul`], i.e. `2` doubles a [`StatInt`](crate::types::StatInt)
+    /// and `200` doubles a [`StatIntPercent`](crate::types::StatIntPercent) with a `SCALE` of `100`.
+    ///
+    /// Unlike [`StatValue::mul`], the multiplier tracks are not affected,
+    /// so a scaled value only scales its own contribution when joined with another.
+    /// Bounds are not affected.
+    ///
+    /// By default this is the same as [`StatValue::mul`],
+    /// which is only correct for types without an additive part.
+    fn scale(&mut self, factor: Self::Mul) {
+        self.mul(factor)
+    }
     fn or(&mut self, other: Self::Bit) {}
 
     fn min(&mut self, other: Self::Bounds) {}
     fn max(&mut self, other: Self::Bounds) {}
 
+    /// Returns the upper bound the evaluated value is clamped to, if set.
+    ///
+    /// Useful for displaying the maximum of a stat like health.
+    fn upper_bound(&self) -> Option<Self::Bounds> {
+        None
+    }
+
+    /// Returns true if [`StatValue::eval`] is at or beyond its minimum or maximum bound,
+    /// i.e. changes in that direction have no effect.
+    ///
+    /// Useful for UI feedback, e.g. showing a stat is capped.
+    fn is_clamped(&self) -> bool {
+        false
+    }
+
+    /// Shift a stored value by the difference between an old and a new default,
+    /// used in [`StatMap::rebase`](crate::StatMap::rebase).
+    ///
+    /// By default does nothing, numeric types shift their additive part.
+    fn rebase(&mut self, old: &Self, new: &Self) {}
+
+    /// Join `other` contributing `weight` of the result, e.g. `Fraction::new(3, 10)` for 30%.
+    ///
+    /// Numeric types interpolate their additive part towards `other` and join everything else.
+    /// This is order-dependent, blending `a` into `b` is not the same as `b` into `a`.
+    ///
+    /// By default joins without weighting.
+    fn weighted_join(&mut self, other: Self, weight: Fraction<i32>) {
+        self.join(other)
+    }
+
+    /// Normalize redundant internal state without changing the result of
+    /// [`StatValue::eval`], [`StatValue::is_clamped`], [`StatValue::into_base`] or future joins,
+    /// so equal values serialize identically.
+    ///
+    /// Called on a copy of the value before serialization in [`StatMap`](crate::StatMap),
+    /// if [`StatValue::is_canonical`] returns false.
+    fn canonicalize(&mut self) {}
+
+    /// Returns false if [`StatValue::canonicalize`] would change the value,
+    /// should be implemented alongside it.
+    fn is_canonical(&self) -> bool {
+        true
+    }
+
+    /// Pass the evaluated value to a typed callback, used by [`StatMap::visit`](crate::StatMap::visit).
+    ///
+    /// By default passes [`EvalValue::Other`].
+    fn visit_eval(&self, f: &mut dyn FnMut(EvalValue)) {
+        f(EvalValue::Other(&self.eval()))
+    }
+
     fn with_add(mut self, other: Self::Add) -> Self {
         self.add(other);
         self
@@ -101,6 +265,31 @@ pub trait StatValue: Shareable + Default {
     }
 
     fn from_base(base: Self::Base) -> Self;
+
+    /// Inverse of [`StatValue::from_base`], returns the base a value was created from.
+    ///
+    /// Only meaningful on values fresh from [`StatValue::from_base`], since
+    /// other operations are not reflected in the result. By default returns `None`.
+    fn into_base(self) -> Option<Self::Base> {
+        None
+    }
+
+    /// Simplify a list of [`StatOperation`]s without changing the result of applying them,
+    /// by merging operations of the same kind where the value type allows it.
+    ///
+    /// By default only discards operations overwritten by a later [`StatOperation::Base`].
+    fn simplify_ops(ops: Vec<StatOperation<Self>>) -> Vec<StatOperation<Self>> {
+        OpMerger::NONE.simplify(ops)
+    }
+
+    /// Construct a value by applying [`StatOperation`]s in order to [`Default::default`].
+    fn from_operations(ops: impl IntoIterator<Item = StatOperation<Self>>) -> Self {
+        let mut value = Self::default();
+        for op in ops {
+            op.write_to(&mut value);
+        }
+        value
+    }
 }
 
 impl StatValue for bool {
@@ -127,4 +316,20 @@ impl StatValue for bool {
     fn from_base(base: Self::Base) -> Self {
         base
     }
+
+    fn into_base(self) -> Option<Self::Base> {
+        Some(self)
+    }
+
+    fn visit_eval(&self, f: &mut dyn FnMut(EvalValue)) {
+        f(EvalValue::Bool(*self))
+    }
+
+    fn simplify_ops(ops: Vec<StatOperation<Self>>) -> Vec<StatOperation<Self>> {
+        OpMerger {
+            or: Some(|a, b| a | b),
+            ..OpMerger::NONE
+        }
+        .simplify(ops)
+    }
 }
@@ -1,17 +1,25 @@
 use std::fmt::Debug;
 
 use crate::operations::StatOperation;
+use crate::types::{
+    StatAll, StatComposite, StatFlags, StatFlagsOverride, StatFloat, StatFloatAdditive,
+    StatFloatGuarded, StatFloatRounded, StatInt, StatIntPercent, StatIntPercentAdditive,
+    StatIntRounded, StatLayered, StatMaxAgg, StatMinAgg, StatMult, StatPercentSaturating,
+    StatVector, StatWeightedMean,
+};
 use crate::StatInst;
 use crate::{
-    Buffer, QualifierFlag, QualifierQuery, Querier, Stat, StatExt, StatStream, StatValue,
-    StatValuePair,
+    Buffer, Fraction, QualifierFlag, QualifierQuery, Querier, Stat, StatExt, StatMap, StatStream,
+    StatValue, StatValuePair,
 };
 use bevy_app::App;
 use bevy_ecs::entity::Entity;
+use bevy_ecs::reflect::AppTypeRegistry;
 use bevy_ecs::system::Resource;
 use bevy_ecs::world::World;
 use bevy_reflect::TypePath;
 use rustc_hash::FxHashMap;
+use serde::{de::DeserializeOwned, Serialize};
 
 type Bounds<T> = <<T as Stat>::Value as StatValue>::Bounds;
 
@@ -24,6 +32,14 @@ pub trait StatExtension {
     /// If trying to replace a previous stat entry with a different value.
     fn register_stat<T: Stat>(&mut self) -> &mut Self;
 
+    /// Register associated serialization routine for a stat,
+    /// and ensure [`GlobalStatDefaults`] and [`GlobalStatRelations<Q>`] exist.
+    ///
+    /// # Panics
+    ///
+    /// If trying to replace a previous stat entry with a different value.
+    fn register_stat_for<T: Stat, Q: QualifierFlag>(&mut self) -> &mut Self;
+
     /// Register a default stat value.
     ///
     /// This is the standard way
@@ -45,6 +61,20 @@ pub trait StatExtension {
             + Sync
             + 'static,
     ) -> &mut Self;
+
+    /// Register a global stat relation
+    /// that will only be run when querying `stat`.
+    fn register_stat_relation_for<S: Stat, Q: QualifierFlag>(
+        &mut self,
+        stat: S,
+        relation: impl Fn(Entity, &QualifierQuery<Q>, &mut S::Value, Querier<Q>) + Send + Sync + 'static,
+    ) -> &mut Self;
+
+    /// Register reflection for built-in [`StatValue`] types on common number types,
+    /// [`Fraction<i32>`] and [`StatMap<Q>`].
+    fn register_stat_reflect_types<Q>(&mut self) -> &mut Self
+    where
+        Q: QualifierFlag + TypePath + Serialize + DeserializeOwned;
 }
 
 impl StatExtension for World {
@@ -54,6 +84,13 @@ impl StatExtension for World {
         self
     }
 
+    fn register_stat_for<T: Stat, Q: QualifierFlag>(&mut self) -> &mut Self {
+        self.register_stat::<T>();
+        self.get_resource_or_insert_with::<GlobalStatDefaults>(Default::default);
+        self.get_resource_or_insert_with(GlobalStatRelations::<Q>::default);
+        self
+    }
+
     fn register_stat_default<S: Stat>(&mut self, stat: S, value: S::Value) -> &mut Self {
         self.get_resource_or_insert_with::<GlobalStatDefaults>(Default::default)
             .insert(stat, value);
@@ -83,6 +120,57 @@ impl StatExtension for World {
             .push(relation);
         self
     }
+
+    fn register_stat_relation_for<S: Stat, Q: QualifierFlag>(
+        &mut self,
+        stat: S,
+        relation: impl Fn(Entity, &QualifierQuery<Q>, &mut S::Value, Querier<Q>) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.get_resource_or_insert_with(GlobalStatRelations::<Q>::default)
+            .push_for(stat, relation);
+        self
+    }
+
+    fn register_stat_reflect_types<Q>(&mut self) -> &mut Self
+    where
+        Q: QualifierFlag + TypePath + Serialize + DeserializeOwned,
+    {
+        let registry = self
+            .get_resource_or_insert_with::<AppTypeRegistry>(Default::default)
+            .clone();
+        let mut registry = registry.write();
+        registry.register::<StatFloat<f32>>();
+        registry.register::<StatFloatAdditive<f32>>();
+        registry.register::<StatFloatGuarded<f32>>();
+        registry.register::<StatFloatRounded<f32>>();
+        registry.register::<StatComposite<f32>>();
+        registry.register::<StatLayered<f32>>();
+        registry.register::<StatVector<f32, 3>>();
+        registry.register::<StatWeightedMean<f32>>();
+        #[cfg(feature = "glam")]
+        registry.register::<crate::types::StatVec2>();
+        #[cfg(feature = "glam")]
+        registry.register::<crate::types::StatVec3>();
+        registry.register::<StatMult<f32>>();
+        registry.register::<StatMult<f64>>();
+        registry.register::<StatMaxAgg<f32>>();
+        registry.register::<StatMinAgg<f32>>();
+        registry.register::<StatInt<i32>>();
+        registry.register::<StatInt<u32>>();
+        registry.register::<StatIntRounded<i32, f32>>();
+        registry.register::<StatIntRounded<i32, Fraction<i32>>>();
+        registry.register::<StatIntPercent<i32>>();
+        registry.register::<StatIntPercentAdditive<i32>>();
+        registry.register::<StatPercentSaturating<i32>>();
+        registry.register::<StatFlags<u32>>();
+        registry.register::<StatFlags<u64>>();
+        registry.register::<StatFlagsOverride<u32>>();
+        registry.register::<StatFlagsOverride<u64>>();
+        registry.register::<StatAll>();
+        registry.register::<Fraction<i32>>();
+        registry.register::<StatMap<Q>>();
+        self
+    }
 }
 
 impl StatExtension for App {
@@ -91,6 +179,11 @@ impl StatExtension for App {
         self
     }
 
+    fn register_stat_for<T: Stat, Q: QualifierFlag>(&mut self) -> &mut Self {
+        self.world_mut().register_stat_for::<T, Q>();
+        self
+    }
+
     fn register_stat_default<S: Stat>(&mut self, stat: S, value: S::Value) -> &mut Self {
         self.world_mut().register_stat_default::<S>(stat, value);
         self
@@ -116,6 +209,23 @@ impl StatExtension for App {
         self.world_mut().register_stat_relation(relation);
         self
     }
+
+    fn register_stat_relation_for<S: Stat, Q: QualifierFlag>(
+        &mut self,
+        stat: S,
+        relation: impl Fn(Entity, &QualifierQuery<Q>, &mut S::Value, Querier<Q>) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.world_mut().register_stat_relation_for(stat, relation);
+        self
+    }
+
+    fn register_stat_reflect_types<Q>(&mut self) -> &mut Self
+    where
+        Q: QualifierFlag + TypePath + Serialize + DeserializeOwned,
+    {
+        self.world_mut().register_stat_reflect_types::<Q>();
+        self
+    }
 }
 
 /// [`Resource`] that stores default [`StatValue`]s per [`Stat`].
@@ -159,7 +269,7 @@ impl GlobalStatDefaults {
                 self.stats.insert(stat, {
                     let mut stat = S::Value::default();
                     value.write_to(&mut stat);
-                    Buffer::from(value)
+                    Buffer::from(stat)
                 });
             }
         }
@@ -194,8 +304,11 @@ impl Drop for GlobalStatDefaults {
 /// [`Resource`] that stores global [`StatStream`]s that runs on every query.
 #[derive(Resource, TypePath)]
 pub struct GlobalStatRelations<Q: QualifierFlag> {
-    stats:
-        Vec<Box<dyn Fn(Entity, &QualifierQuery<Q>, &mut StatValuePair, Querier<Q>) + Send + Sync>>,
+    #[allow(clippy::type_complexity)]
+    stats: Vec<(
+        Option<StatInst>,
+        Box<dyn Fn(Entity, &QualifierQuery<Q>, &mut StatValuePair, Querier<Q>) + Send + Sync>,
+    )>,
 }
 
 impl<Q: QualifierFlag> Debug for GlobalStatRelations<Q> {
@@ -219,7 +332,24 @@ impl<Q: QualifierFlag> GlobalStatRelations<Q> {
             + Sync
             + 'static,
     ) -> &mut Self {
-        self.stats.push(Box::new(stream));
+        self.stats.push((None, Box::new(stream)));
+        self
+    }
+
+    /// Push a relation that only runs when querying `stat`.
+    pub fn push_for<S: Stat>(
+        &mut self,
+        stat: S,
+        stream: impl Fn(Entity, &QualifierQuery<Q>, &mut S::Value, Querier<Q>) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.stats.push((
+            Some(stat.as_entry()),
+            Box::new(move |entity, qualifier, stat_value, querier| {
+                if let Some(value) = stat_value.is_then_cast(&stat) {
+                    stream(entity, qualifier, value, querier)
+                }
+            }),
+        ));
         self
     }
 
@@ -230,7 +360,7 @@ impl<Q: QualifierFlag> GlobalStatRelations<Q> {
             + Sync
             + 'static,
     ) -> Self {
-        self.stats.push(Box::new(stream));
+        self.stats.push((None, Box::new(stream)));
         self
     }
 }
@@ -245,14 +375,17 @@ impl<Q: QualifierFlag> StatStream for GlobalStatRelations<Q> {
         stat_value: &mut crate::StatValuePair,
         querier: crate::Querier<Q>,
     ) {
-        for f in self.stats.iter() {
+        for (stat, f) in self.stats.iter() {
+            if stat.is_some_and(|stat| stat != stat_value.stat) {
+                continue;
+            }
             f(entity, qualifier, stat_value, querier)
         }
     }
 }
 
 /// Resource containing a name to instance map of [`Stat`]s.
-#[derive(Resource, Default)]
+#[derive(Resource, Default, Clone)]
 pub struct StatDeserializers {
     pub(crate) concrete: FxHashMap<&'static str, StatInst>,
 }
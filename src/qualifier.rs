@@ -55,6 +55,31 @@ where
     }
 }
 
+/// Combines two orthogonal [`QualifierFlag`]s, like element and damage type, into one.
+///
+/// Operations are component-wise, so this behaves like a single flag
+/// containing the bits of both components.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub struct QualifierPair<A, B>(pub A, pub B);
+
+impl<A: BitOr<A, Output = A>, B: BitOr<B, Output = B>> BitOr for QualifierPair<A, B> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        QualifierPair(self.0 | rhs.0, self.1 | rhs.1)
+    }
+}
+
+impl<A: BitAnd<A, Output = A>, B: BitAnd<B, Output = B>> BitAnd for QualifierPair<A, B> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        QualifierPair(self.0 & rhs.0, self.1 & rhs.1)
+    }
+}
+
 /// Data side qualifier for a stat.
 ///
 /// # When stored
@@ -154,6 +179,8 @@ impl<Q: QualifierFlag> Qualifier<Q> {
             QualifierQuery::Exact { any_of, all_of } => {
                 self.any_of.contains(any_of) && &self.all_of == all_of
             }
+            QualifierQuery::Any => true,
+            QualifierQuery::Never => false,
         }
     }
 }
@@ -172,6 +199,10 @@ pub enum QualifierQuery<Q: QualifierFlag> {
         /// Queried `all_of` equals this.
         all_of: Q,
     },
+    /// Look for all qualifiers, useful for displaying every modifier.
+    Any,
+    /// Matches no qualifiers.
+    Never,
 }
 
 impl<Q: QualifierFlag> QualifierQuery<Q> {
@@ -194,6 +225,16 @@ impl<Q: QualifierFlag> QualifierQuery<Q> {
     pub fn none() -> Self {
         Self::Aggregate(Q::none())
     }
+
+    /// Qualifies all stored qualifiers.
+    pub fn any() -> Self {
+        Self::Any
+    }
+
+    /// Qualifies no stored qualifiers.
+    pub fn never() -> Self {
+        Self::Never
+    }
 }
 
 impl<Q: QualifierFlag> From<Q> for QualifierQuery<Q> {
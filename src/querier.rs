@@ -6,13 +6,14 @@ use crate::stat::StatExt;
 use crate::{
     plugin::GlobalStatDefaults, Buffer, QualifierFlag, QualifierQuery, Stat, StatInst, StatStream,
 };
-use crate::{validate, StatValue, StatValuePair};
+use crate::{validate, StatError, StatValue, StatValuePair};
 use bevy_ecs::reflect::ReflectComponent;
 use bevy_ecs::{
     component::Component,
     entity::Entity,
     query::With,
     system::{Query, Res, SystemParam},
+    world::World,
 };
 use bevy_hierarchy::Children;
 use bevy_reflect::Reflect;
@@ -65,6 +66,7 @@ impl<'w, 's, 't, Q: QualifierFlag, S: StatStream<Qualifier = Q>> JoinedQuerier<'
         stat: &T,
     ) -> Option<T::Value> {
         self.query_stat_erased(entity, qualifier, stat.as_entry())
+            .0
             .map(|x| unsafe { x.into() })
     }
 
@@ -76,6 +78,7 @@ impl<'w, 's, 't, Q: QualifierFlag, S: StatStream<Qualifier = Q>> JoinedQuerier<'
         stat: &T,
     ) -> Option<T::Value> {
         self.query_relation_erased(from, to, qualifier, stat.as_entry())
+            .0
             .map(|x| unsafe { x.into() })
     }
 
@@ -85,7 +88,8 @@ impl<'w, 's, 't, Q: QualifierFlag, S: StatStream<Qualifier = Q>> JoinedQuerier<'
         qualifier: &QualifierQuery<Q>,
         stat: &T,
     ) -> Option<<T::Value as StatValue>::Out> {
-        self.query_stat(entity, qualifier, stat).map(|x| x.eval())
+        self.query_stat(entity, qualifier, stat)
+            .map(StatValue::finish)
     }
 
     pub fn eval_relation<T: Stat>(
@@ -96,7 +100,62 @@ impl<'w, 's, 't, Q: QualifierFlag, S: StatStream<Qualifier = Q>> JoinedQuerier<'
         stat: &T,
     ) -> Option<<T::Value as StatValue>::Out> {
         self.query_relation(from, to, qualifier, stat)
-            .map(|x| x.eval())
+            .map(StatValue::finish)
+    }
+
+    /// Evaluate a stat as if `extra` were joined, without modifying `self` or the world.
+    ///
+    /// Useful for previews, e.g. damage if a weapon were equipped.
+    pub fn eval_with<T: StatStream<Qualifier = Q>, U: Stat>(
+        &self,
+        extra: T,
+        entity: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat: &U,
+    ) -> Option<<U::Value as StatValue>::Out> {
+        JoinedQuerier {
+            base: self.base,
+            stream: (&self.stream, extra),
+        }
+        .eval_stat(entity, qualifier, stat)
+    }
+
+    /// Evaluate a stat, returning the first error from [`StatStream::try_stream_stat`].
+    pub fn try_eval_stat<T: Stat>(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat: &T,
+    ) -> Result<<T::Value as StatValue>::Out, StatError> {
+        Querier(self).try_eval_stat(entity, qualifier, stat)
+    }
+
+    /// Evaluate a relation stat, returning the first error from [`StatStream::try_stream_relation`].
+    pub fn try_eval_relation<T: Stat>(
+        &self,
+        from: Entity,
+        to: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat: &T,
+    ) -> Result<<T::Value as StatValue>::Out, StatError> {
+        Querier(self).try_eval_relation(from, to, qualifier, stat)
+    }
+
+    /// Evaluate a stat under multiple [`QualifierQuery`]s,
+    /// e.g. damage against each element on a character screen.
+    pub fn eval_sweep<T: Stat>(
+        &self,
+        entity: Entity,
+        qualifiers: &[QualifierQuery<Q>],
+        stat: &T,
+    ) -> Vec<Option<<T::Value as StatValue>::Out>> {
+        Querier(self).eval_sweep(entity, qualifiers, stat)
+    }
+
+    /// Obtain the default value of a stat without applying any streams or relations,
+    /// e.g. as the reference point of "base vs effective" in UI.
+    pub fn base_value<T: Stat>(&self, stat: &T) -> T::Value {
+        Querier(self).base_value(stat)
     }
 
     pub fn has_attribute<'a>(&self, entity: Entity, attribute: impl Into<Attribute<'a>>) -> bool {
@@ -112,19 +171,329 @@ impl<Q: QualifierFlag, S: StatStream<Qualifier = Q>> ErasedQuerier<Q>
         entity: Entity,
         query: &QualifierQuery<Q>,
         stat: StatInst,
-    ) -> Option<Buffer> {
-        let value = if let Some(defaults) = &self.base.defaults {
+    ) -> (Option<Buffer>, Result<(), StatError>) {
+        let (pair, result) = stream_stat_erased(
+            Querier(self),
+            self.base.defaults.as_deref(),
+            self.base.relations.as_deref(),
+            &self.stream,
+            entity,
+            query,
+            stat,
+        );
+        (Some(pair.into_buffer()), result)
+    }
+
+    fn query_relation_erased(
+        &self,
+        from: Entity,
+        to: Entity,
+        query: &QualifierQuery<Q>,
+        stat: StatInst,
+    ) -> (Option<Buffer>, Result<(), StatError>) {
+        stream_relation_erased(
+            Querier(self),
+            self.base.defaults.as_deref(),
+            &self.stream,
+            from,
+            to,
+            query,
+            stat,
+        )
+    }
+
+    fn sweep_stat_erased(
+        &self,
+        entity: Entity,
+        queries: &[QualifierQuery<Q>],
+        stat: StatInst,
+    ) -> Vec<Option<Buffer>> {
+        sweep_stat_erased(
+            Querier(self),
+            self.base.defaults.as_deref(),
+            self.base.relations.as_deref(),
+            &self.stream,
+            entity,
+            queries,
+            stat,
+        )
+    }
+
+    fn defaults(&self) -> Option<&GlobalStatDefaults> {
+        self.base.defaults.as_deref()
+    }
+
+    fn has_attribute_erased(&self, entity: Entity, attribute: Attribute) -> bool {
+        self.stream.has_attribute(entity, attribute)
+    }
+}
+
+fn stream_stat_erased<Q: QualifierFlag, S: StatStream<Qualifier = Q>>(
+    querier: Querier<Q>,
+    defaults: Option<&GlobalStatDefaults>,
+    relations: Option<&GlobalStatRelations<Q>>,
+    stream: &S,
+    entity: Entity,
+    query: &QualifierQuery<Q>,
+    stat: StatInst,
+) -> (StatValuePair, Result<(), StatError>) {
+    let value = if let Some(defaults) = defaults {
+        defaults.get_dyn(stat)
+    } else {
+        (stat.vtable.default)()
+    };
+    let mut pair = StatValuePair { stat, value };
+    let result = stream_stat_into(querier, relations, stream, entity, query, &mut pair);
+    (pair, result)
+}
+
+/// Same as [`stream_stat_erased`] for multiple [`QualifierQuery`]s,
+/// fetching the default value only once.
+fn sweep_stat_erased<Q: QualifierFlag, S: StatStream<Qualifier = Q>>(
+    querier: Querier<Q>,
+    defaults: Option<&GlobalStatDefaults>,
+    relations: Option<&GlobalStatRelations<Q>>,
+    stream: &S,
+    entity: Entity,
+    queries: &[QualifierQuery<Q>],
+    stat: StatInst,
+) -> Vec<Option<Buffer>> {
+    let default = StatValuePair {
+        stat,
+        value: if let Some(defaults) = defaults {
             defaults.get_dyn(stat)
         } else {
             (stat.vtable.default)()
-        };
-        let mut pair = StatValuePair { stat, value };
-        if let Some(relations) = &self.base.relations {
-            relations.stream_stat(entity, query, &mut pair, Querier(self));
+        },
+    };
+    queries
+        .iter()
+        .map(|query| {
+            let mut pair = StatValuePair {
+                stat,
+                value: unsafe { (stat.vtable.clone)(&default.value) },
+            };
+            let _ = stream_stat_into(querier, relations, stream, entity, query, &mut pair);
+            Some(pair.into_buffer())
+        })
+        .collect()
+}
+
+fn stream_stat_into<Q: QualifierFlag, S: StatStream<Qualifier = Q>>(
+    querier: Querier<Q>,
+    relations: Option<&GlobalStatRelations<Q>>,
+    stream: &S,
+    entity: Entity,
+    query: &QualifierQuery<Q>,
+    pair: &mut StatValuePair,
+) -> Result<(), StatError> {
+    if let Some(relations) = relations {
+        relations.stream_stat(entity, query, pair, querier);
+    }
+    if !stream.applies_to(pair.stat) {
+        return Ok(());
+    }
+    let result = stream.try_stream_stat(entity, query, pair, querier);
+    stream.finalize(entity, query, pair, querier);
+    result
+}
+
+fn stream_relation_erased<Q: QualifierFlag, S: StatStream<Qualifier = Q>>(
+    querier: Querier<Q>,
+    defaults: Option<&GlobalStatDefaults>,
+    stream: &S,
+    from: Entity,
+    to: Entity,
+    query: &QualifierQuery<Q>,
+    stat: StatInst,
+) -> (Option<Buffer>, Result<(), StatError>) {
+    let value = if let Some(defaults) = defaults {
+        defaults.get_dyn(stat)
+    } else {
+        (stat.vtable.default)()
+    };
+    let mut pair = StatValuePair { stat, value };
+    let mut result = Ok(());
+    if stream.applies_to(stat) {
+        result = stream.try_stream_relation(stream, from, to, query, &mut pair, querier);
+        stream.finalize(from, query, &mut pair, querier);
+    }
+    (Some(pair.into_buffer()), result)
+}
+
+/// A querier constructed from references instead of as a [`SystemParam`].
+///
+/// This allows querying in exclusive systems and tests,
+/// where [`StatEntities`] cannot be obtained.
+pub struct OwnedQuerier<'t, Q: QualifierFlag, S: StatStream<Qualifier = Q>> {
+    defaults: Option<&'t GlobalStatDefaults>,
+    relations: Option<&'t GlobalStatRelations<Q>>,
+    stream: S,
+}
+
+impl<'t, Q: QualifierFlag, S: StatStream<Qualifier = Q>> OwnedQuerier<'t, Q, S> {
+    /// Create a querier without global defaults or relations.
+    pub fn new(stream: S) -> Self {
+        OwnedQuerier {
+            defaults: None,
+            relations: None,
+            stream,
+        }
+    }
+
+    /// Create a querier using [`GlobalStatDefaults`] and [`GlobalStatRelations`]
+    /// from a [`World`] if present.
+    ///
+    /// Use [`WorldStream`](crate::WorldStream) to stream components from the [`World`].
+    pub fn from_world(world: &'t World, stream: S) -> Self {
+        OwnedQuerier {
+            defaults: world.get_resource(),
+            relations: world.get_resource(),
+            stream,
         }
-        self.stream
-            .stream_stat(entity, query, &mut pair, Querier(self));
-        Some(pair.value)
+    }
+
+    /// Use a [`GlobalStatDefaults`] for default values.
+    pub fn with_defaults(mut self, defaults: &'t GlobalStatDefaults) -> Self {
+        self.defaults = Some(defaults);
+        self
+    }
+
+    /// Run a [`GlobalStatRelations`] on every query.
+    pub fn with_relations(mut self, relations: &'t GlobalStatRelations<Q>) -> Self {
+        self.relations = Some(relations);
+        self
+    }
+
+    pub fn join<T: StatStream<Qualifier = Q>>(self, stream: T) -> OwnedQuerier<'t, Q, (S, T)> {
+        OwnedQuerier {
+            defaults: self.defaults,
+            relations: self.relations,
+            stream: (self.stream, stream),
+        }
+    }
+
+    /// Obtain a type erased [`Querier`].
+    pub fn querier(&self) -> Querier<'_, Q> {
+        Querier(self)
+    }
+
+    pub fn query_stat<T: Stat>(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat: &T,
+    ) -> Option<T::Value> {
+        self.querier().query_stat(entity, qualifier, stat)
+    }
+
+    pub fn query_relation<T: Stat>(
+        &self,
+        from: Entity,
+        to: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat: &T,
+    ) -> Option<T::Value> {
+        self.querier().query_relation(from, to, qualifier, stat)
+    }
+
+    pub fn eval_stat<T: Stat>(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat: &T,
+    ) -> Option<<T::Value as StatValue>::Out> {
+        self.querier().eval_stat(entity, qualifier, stat)
+    }
+
+    pub fn eval_relation<T: Stat>(
+        &self,
+        from: Entity,
+        to: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat: &T,
+    ) -> Option<<T::Value as StatValue>::Out> {
+        self.querier().eval_relation(from, to, qualifier, stat)
+    }
+
+    /// Evaluate a stat as if `extra` were joined, without modifying `self`.
+    ///
+    /// Useful for previews, e.g. damage if a weapon were equipped.
+    pub fn eval_with<T: StatStream<Qualifier = Q>, U: Stat>(
+        &self,
+        extra: T,
+        entity: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat: &U,
+    ) -> Option<<U::Value as StatValue>::Out> {
+        OwnedQuerier {
+            defaults: self.defaults,
+            relations: self.relations,
+            stream: (&self.stream, extra),
+        }
+        .eval_stat(entity, qualifier, stat)
+    }
+
+    /// Evaluate a stat, returning the first error from [`StatStream::try_stream_stat`].
+    pub fn try_eval_stat<T: Stat>(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat: &T,
+    ) -> Result<<T::Value as StatValue>::Out, StatError> {
+        self.querier().try_eval_stat(entity, qualifier, stat)
+    }
+
+    /// Evaluate a relation stat, returning the first error from [`StatStream::try_stream_relation`].
+    pub fn try_eval_relation<T: Stat>(
+        &self,
+        from: Entity,
+        to: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat: &T,
+    ) -> Result<<T::Value as StatValue>::Out, StatError> {
+        self.querier().try_eval_relation(from, to, qualifier, stat)
+    }
+
+    /// Evaluate a stat under multiple [`QualifierQuery`]s,
+    /// e.g. damage against each element on a character screen.
+    pub fn eval_sweep<T: Stat>(
+        &self,
+        entity: Entity,
+        qualifiers: &[QualifierQuery<Q>],
+        stat: &T,
+    ) -> Vec<Option<<T::Value as StatValue>::Out>> {
+        self.querier().eval_sweep(entity, qualifiers, stat)
+    }
+
+    /// Obtain the default value of a stat without applying any streams or relations,
+    /// e.g. as the reference point of "base vs effective" in UI.
+    pub fn base_value<T: Stat>(&self, stat: &T) -> T::Value {
+        self.querier().base_value(stat)
+    }
+
+    pub fn has_attribute<'a>(&self, entity: Entity, attribute: impl Into<Attribute<'a>>) -> bool {
+        self.querier().has_attribute(entity, attribute)
+    }
+}
+
+impl<Q: QualifierFlag, S: StatStream<Qualifier = Q>> ErasedQuerier<Q> for OwnedQuerier<'_, Q, S> {
+    fn query_stat_erased(
+        &self,
+        entity: Entity,
+        query: &QualifierQuery<Q>,
+        stat: StatInst,
+    ) -> (Option<Buffer>, Result<(), StatError>) {
+        let (pair, result) = stream_stat_erased(
+            Querier(self),
+            self.defaults,
+            self.relations,
+            &self.stream,
+            entity,
+            query,
+            stat,
+        );
+        (Some(pair.into_buffer()), result)
     }
 
     fn query_relation_erased(
@@ -133,16 +502,37 @@ impl<Q: QualifierFlag, S: StatStream<Qualifier = Q>> ErasedQuerier<Q>
         to: Entity,
         query: &QualifierQuery<Q>,
         stat: StatInst,
-    ) -> Option<Buffer> {
-        let value = if let Some(defaults) = &self.base.defaults {
-            defaults.get_dyn(stat)
-        } else {
-            (stat.vtable.default)()
-        };
-        let mut pair = StatValuePair { stat, value };
-        self.stream
-            .stream_relation(&self.stream, from, to, query, &mut pair, Querier(self));
-        Some(pair.value)
+    ) -> (Option<Buffer>, Result<(), StatError>) {
+        stream_relation_erased(
+            Querier(self),
+            self.defaults,
+            &self.stream,
+            from,
+            to,
+            query,
+            stat,
+        )
+    }
+
+    fn sweep_stat_erased(
+        &self,
+        entity: Entity,
+        queries: &[QualifierQuery<Q>],
+        stat: StatInst,
+    ) -> Vec<Option<Buffer>> {
+        sweep_stat_erased(
+            Querier(self),
+            self.defaults,
+            self.relations,
+            &self.stream,
+            entity,
+            queries,
+            stat,
+        )
+    }
+
+    fn defaults(&self) -> Option<&GlobalStatDefaults> {
+        self.defaults
     }
 
     fn has_attribute_erased(&self, entity: Entity, attribute: Attribute) -> bool {
@@ -152,24 +542,38 @@ impl<Q: QualifierFlag, S: StatStream<Qualifier = Q>> ErasedQuerier<Q>
 
 /// An erased type that can query for stats on entities in the world.
 ///
-/// Notable implementors are [`NoopQuerier`] and [`JoinedQuerier`].
+/// Notable implementors are [`NoopQuerier`], [`JoinedQuerier`] and [`OwnedQuerier`].
 trait ErasedQuerier<Q: QualifierFlag> {
-    /// Query for a stat in its component form.
+    /// Query for a stat in its component form,
+    /// and the first error from [`StatStream::try_stream_stat`].
     fn query_stat_erased(
         &self,
         entity: Entity,
         query: &QualifierQuery<Q>,
         stat: StatInst,
-    ) -> Option<Buffer>;
+    ) -> (Option<Buffer>, Result<(), StatError>);
 
-    /// Query for a relation stat in its component form.
+    /// Query for a relation stat in its component form,
+    /// and the first error from [`StatStream::try_stream_relation`].
     fn query_relation_erased(
         &self,
         from: Entity,
         to: Entity,
         query: &QualifierQuery<Q>,
         stat: StatInst,
-    ) -> Option<Buffer>;
+    ) -> (Option<Buffer>, Result<(), StatError>);
+
+    /// Query for a stat in its component form under multiple [`QualifierQuery`]s,
+    /// the same as calling [`ErasedQuerier::query_stat_erased`] on each.
+    fn sweep_stat_erased(
+        &self,
+        entity: Entity,
+        queries: &[QualifierQuery<Q>],
+        stat: StatInst,
+    ) -> Vec<Option<Buffer>>;
+
+    /// Obtain the [`GlobalStatDefaults`] used, if any.
+    fn defaults(&self) -> Option<&GlobalStatDefaults>;
 
     /// Query for the existence of a string attribute.
     fn has_attribute_erased(&self, entity: Entity, attribute: Attribute) -> bool;
@@ -209,6 +613,7 @@ impl<Q: QualifierFlag> Querier<'_, Q> {
         validate::<S::Value>();
         self.0
             .query_stat_erased(entity, qualifier, stat.as_entry())
+            .0
             .map(|x| unsafe { x.into() })
     }
 
@@ -223,6 +628,7 @@ impl<Q: QualifierFlag> Querier<'_, Q> {
         validate::<S::Value>();
         self.0
             .query_relation_erased(from, to, qualifier, stat.as_entry())
+            .0
             .map(|x| unsafe { x.into() })
     }
 
@@ -235,7 +641,61 @@ impl<Q: QualifierFlag> Querier<'_, Q> {
     ) -> Option<<S::Value as StatValue>::Out> {
         validate::<S::Value>();
         self.query_stat(entity, qualifier, stat)
-            .map(|x| StatValue::eval(&x))
+            .map(StatValue::finish)
+    }
+
+    /// Query for a stat in its evaluated form, for use with `?` in [`StatStream::try_stream_stat`].
+    ///
+    /// Returns the first error from [`StatStream::try_stream_stat`] of the queried streams.
+    pub fn try_eval_stat<S: Stat>(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat: &S,
+    ) -> Result<<S::Value as StatValue>::Out, StatError> {
+        validate::<S::Value>();
+        let (buffer, result) = self.0.query_stat_erased(entity, qualifier, stat.as_entry());
+        let value = buffer.map(|x| unsafe { x.into::<S::Value>() });
+        result?;
+        Ok(value
+            .expect("Value is present if no error occurred.")
+            .finish())
+    }
+
+    /// Query for the upper bound of a stat, see [`StatValue::upper_bound`].
+    pub fn eval_stat_max<S: Stat>(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat: &S,
+    ) -> Option<<S::Value as StatValue>::Bounds> {
+        self.query_stat(entity, qualifier, stat)?.upper_bound()
+    }
+
+    /// Query if a stat is clamped by its bounds, see [`StatValue::is_clamped`].
+    pub fn is_stat_clamped<S: Stat>(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat: &S,
+    ) -> Option<bool> {
+        Some(self.query_stat(entity, qualifier, stat)?.is_clamped())
+    }
+
+    /// Query for multiple stats in their evaluated form.
+    ///
+    /// Returns [`None`] if any of the queries failed.
+    pub fn eval_all<S: Stat, const N: usize>(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stats: [&S; N],
+    ) -> Option<[<S::Value as StatValue>::Out; N]> {
+        let values = stats.map(|stat| self.eval_stat(entity, qualifier, stat));
+        if values.iter().any(Option::is_none) {
+            return None;
+        }
+        Some(values.map(Option::unwrap))
     }
 
     /// Query for a relation stat in its evaluated form.
@@ -248,7 +708,54 @@ impl<Q: QualifierFlag> Querier<'_, Q> {
     ) -> Option<<S::Value as StatValue>::Out> {
         validate::<S::Value>();
         self.query_relation(from, to, qualifier, stat)
-            .map(|x| StatValue::eval(&x))
+            .map(StatValue::finish)
+    }
+
+    /// Query for a relation stat in its evaluated form,
+    /// for use with `?` in [`StatStream::try_stream_stat`].
+    ///
+    /// Returns the first error from [`StatStream::try_stream_relation`] of the queried streams.
+    pub fn try_eval_relation<S: Stat>(
+        &self,
+        from: Entity,
+        to: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat: &S,
+    ) -> Result<<S::Value as StatValue>::Out, StatError> {
+        validate::<S::Value>();
+        let (buffer, result) = self
+            .0
+            .query_relation_erased(from, to, qualifier, stat.as_entry());
+        let value = buffer.map(|x| unsafe { x.into::<S::Value>() });
+        result?;
+        Ok(value
+            .expect("Value is present if no error occurred.")
+            .finish())
+    }
+
+    /// Evaluate a stat under multiple [`QualifierQuery`]s, in the same order.
+    ///
+    /// Each value is the same as [`Querier::eval_stat`] under that [`QualifierQuery`].
+    pub(crate) fn eval_sweep<S: Stat>(
+        &self,
+        entity: Entity,
+        qualifiers: &[QualifierQuery<Q>],
+        stat: &S,
+    ) -> Vec<Option<<S::Value as StatValue>::Out>> {
+        validate::<S::Value>();
+        self.0
+            .sweep_stat_erased(entity, qualifiers, stat.as_entry())
+            .into_iter()
+            .map(|x| x.map(|x| unsafe { x.into::<S::Value>() }.finish()))
+            .collect()
+    }
+
+    /// Obtain the default value of a stat from [`GlobalStatDefaults`] if present.
+    pub(crate) fn base_value<S: Stat>(&self, stat: &S) -> S::Value {
+        self.0
+            .defaults()
+            .map(|defaults| defaults.get(stat))
+            .unwrap_or_default()
     }
 
     /// Query for the existence of an attribute.
@@ -263,15 +770,42 @@ pub struct NoopQuerier;
 impl<Q: QualifierFlag> ErasedQuerier<Q> for NoopQuerier {
     fn query_relation_erased(
         &self,
-        _: Entity,
-        _: Entity,
+        from: Entity,
+        to: Entity,
+        _: &QualifierQuery<Q>,
+        stat: StatInst,
+    ) -> (Option<Buffer>, Result<(), StatError>) {
+        let error = StatError::RelationFailed {
+            from,
+            to,
+            stat: stat.name(),
+        };
+        (None, Err(error))
+    }
+
+    fn query_stat_erased(
+        &self,
+        entity: Entity,
         _: &QualifierQuery<Q>,
+        stat: StatInst,
+    ) -> (Option<Buffer>, Result<(), StatError>) {
+        let error = StatError::QueryFailed {
+            entity,
+            stat: stat.name(),
+        };
+        (None, Err(error))
+    }
+
+    fn sweep_stat_erased(
+        &self,
+        _: Entity,
+        queries: &[QualifierQuery<Q>],
         _: StatInst,
-    ) -> Option<Buffer> {
-        None
+    ) -> Vec<Option<Buffer>> {
+        queries.iter().map(|_| None).collect()
     }
 
-    fn query_stat_erased(&self, _: Entity, _: &QualifierQuery<Q>, _: StatInst) -> Option<Buffer> {
+    fn defaults(&self) -> Option<&GlobalStatDefaults> {
         None
     }
 
@@ -1,49 +1,52 @@
 use crate::Float;
 use bevy_reflect::TypePath;
+use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
 /// Rounding method for a floating point number.
+///
+/// Stats store their rounding method, which is a zero sized type for static methods.
 pub trait Rounding: TypePath + Default + Debug + Copy + Send + Sync + 'static {
     /// Rounds to the an integer.
-    fn round<F: Float>(input: F) -> F;
+    fn round<F: Float>(self, input: F) -> F;
 }
 
 /// Rounds to 0.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, TypePath)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TypePath)]
 pub struct Truncate;
 
 impl Rounding for Truncate {
-    fn round<F: Float>(input: F) -> F {
+    fn round<F: Float>(self, input: F) -> F {
         input.trunc()
     }
 }
 
 /// Rounds to the largest integer smaller than the float.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, TypePath)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TypePath)]
 pub struct Floor;
 
 impl Rounding for Floor {
-    fn round<F: Float>(input: F) -> F {
+    fn round<F: Float>(self, input: F) -> F {
         input.floor()
     }
 }
 
 /// Rounds to the smallest integer larget than the float.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, TypePath)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TypePath)]
 pub struct Ceil;
 
 impl Rounding for Ceil {
-    fn round<F: Float>(input: F) -> F {
+    fn round<F: Float>(self, input: F) -> F {
         input.ceil()
     }
 }
 
 /// Rounds to the nearest integer.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, TypePath)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TypePath)]
 pub struct Round;
 
 impl Rounding for Round {
-    fn round<F: Float>(input: F) -> F {
+    fn round<F: Float>(self, input: F) -> F {
         input.round()
     }
 }
@@ -51,11 +54,11 @@ impl Rounding for Round {
 /// Rounds `x > 0` to at least `1`,
 /// rounds `x < 0` to at most `-1`.
 /// rounds `x == 0` to `0`.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, TypePath)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TypePath)]
 pub struct TruncateSigned;
 
 impl Rounding for TruncateSigned {
-    fn round<F: Float>(input: F) -> F {
+    fn round<F: Float>(self, input: F) -> F {
         if input > F::ZERO {
             input.trunc().max(F::ONE)
         } else if input < F::ZERO {
@@ -65,3 +68,30 @@ impl Rounding for TruncateSigned {
         }
     }
 }
+
+/// A rounding method chosen at runtime, e.g. from game settings.
+///
+/// The method is stored in the stat, set it via `with_rounding`,
+/// e.g. on the [`GlobalStatDefaults`](crate::GlobalStatDefaults) of a save or difficulty.
+/// Since `join` keeps the method of `self`, a queried stat uses the method of its default value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TypePath)]
+pub enum DynRounding {
+    #[default]
+    Truncate,
+    Floor,
+    Ceil,
+    Round,
+    TruncateSigned,
+}
+
+impl Rounding for DynRounding {
+    fn round<F: Float>(self, input: F) -> F {
+        match self {
+            DynRounding::Truncate => Truncate.round(input),
+            DynRounding::Floor => Floor.round(input),
+            DynRounding::Ceil => Ceil.round(input),
+            DynRounding::Round => Round.round(input),
+            DynRounding::TruncateSigned => TruncateSigned.round(input),
+        }
+    }
+}
@@ -5,13 +5,18 @@ use std::{
     fmt::Debug,
     hash::Hash,
     marker::PhantomData,
+    mem::ManuallyDrop,
     ptr,
+    sync::atomic::{self, AtomicBool},
 };
 
 use bevy_serde_lens_core::with_world_mut;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
-use crate::{plugin::StatDeserializers, validate, Buffer, Shareable, StatValue};
+use crate::{
+    operations::StatOperation, plugin::StatDeserializers, validate, Buffer, EvalValue, Shareable,
+    StatValue,
+};
 
 /// A `vtable` of dynamic functions on [`Stat::Value`].
 #[repr(transparent)]
@@ -28,13 +33,24 @@ pub(crate) struct ErasedStatVTable {
     pub as_debug: unsafe fn(&Buffer) -> &dyn Debug,
     pub as_serialize: unsafe fn(&Buffer) -> &dyn erased_serde::Serialize,
     pub deserialize: fn(&mut dyn erased_serde::Deserializer) -> erased_serde::Result<Buffer>,
+    /// Deserializes a [`StatOperation`] and applies it to the value.
+    pub deserialize_operation:
+        unsafe fn(&mut Buffer, &mut dyn erased_serde::Deserializer) -> erased_serde::Result<()>,
     pub clone: unsafe fn(&Buffer) -> Buffer,
+    /// Returns a canonical copy if the value is not canonical.
+    pub canonicalize: unsafe fn(&Buffer) -> Option<Buffer>,
+    pub visit_eval: unsafe fn(&Buffer, &mut dyn FnMut(EvalValue)),
+    pub eval_debug: unsafe fn(&Buffer) -> Box<dyn Debug>,
+    pub value_type_id: fn() -> TypeId,
     pub drop: unsafe fn(&mut Buffer),
 }
 
 impl StatVTable {
     /// Create a [`StatVTable`] of a given [`Stat`] type, complete with serialization support.
-    pub const fn of<T: Stat<Value: Serialize + DeserializeOwned>>() -> StatVTable<T> {
+    pub const fn of<T: Stat<Value: Serialize + DeserializeOwned>>() -> StatVTable<T>
+    where
+        StatOperation<T::Value>: DeserializeOwned,
+    {
         StatVTable {
             vtable: ErasedStatVTable {
                 name: |id| T::index_to_name(id),
@@ -54,7 +70,24 @@ impl StatVTable {
                     let value: T::Value = erased_serde::deserialize(deserializer)?;
                     Ok(Buffer::from(value))
                 },
+                deserialize_operation: |buffer, deserializer| {
+                    let operation: StatOperation<T::Value> =
+                        erased_serde::deserialize(deserializer)?;
+                    operation.write_to(unsafe { buffer.as_mut::<T::Value>() });
+                    Ok(())
+                },
                 clone: |buffer| Buffer::from(unsafe { buffer.as_ref::<T::Value>() }.clone()),
+                canonicalize: |buffer| {
+                    let value = unsafe { buffer.as_ref::<T::Value>() };
+                    (!value.is_canonical()).then(|| {
+                        let mut value = value.clone();
+                        value.canonicalize();
+                        Buffer::from(value)
+                    })
+                },
+                visit_eval: |buffer, f| unsafe { buffer.as_ref::<T::Value>() }.visit_eval(f),
+                eval_debug: |buffer| Box::new(unsafe { buffer.as_ref::<T::Value>() }.eval()),
+                value_type_id: TypeId::of::<T::Value>,
                 drop: |buffer| {
                     let value = unsafe { buffer.read_move::<T::Value>() };
                     drop(value)
@@ -85,7 +118,19 @@ impl StatVTable {
                 },
                 as_serialize: |_| panic!("Serialization is not supported."),
                 deserialize: |_| panic!("Deserialization is not supported."),
+                deserialize_operation: |_, _| panic!("Deserialization is not supported."),
                 clone: |buffer| Buffer::from(unsafe { buffer.as_ref::<T::Value>() }.clone()),
+                canonicalize: |buffer| {
+                    let value = unsafe { buffer.as_ref::<T::Value>() };
+                    (!value.is_canonical()).then(|| {
+                        let mut value = value.clone();
+                        value.canonicalize();
+                        Buffer::from(value)
+                    })
+                },
+                visit_eval: |buffer, f| unsafe { buffer.as_ref::<T::Value>() }.visit_eval(f),
+                eval_debug: |buffer| Box::new(unsafe { buffer.as_ref::<T::Value>() }.eval()),
+                value_type_id: TypeId::of::<T::Value>,
                 drop: |buffer| {
                     let value = unsafe { buffer.read_move::<T::Value>() };
                     drop(value)
@@ -125,11 +170,21 @@ impl StatInst {
         (self.vtable.name)(self.index)
     }
 
-    pub unsafe fn clone_buffer(&self, buffer: &Buffer) -> Buffer {
+    /// Check for equality with a concrete [`Stat`].
+    pub fn is<S: Stat>(&self, stat: &S) -> bool {
+        *self == stat.as_entry()
+    }
+
+    /// Check if this is an instance of [`Stat`] type `S`.
+    pub fn is_type<S: Stat>(&self) -> bool {
+        ptr::eq(self.vtable, &S::vtable().vtable)
+    }
+
+    pub(crate) unsafe fn clone_buffer(&self, buffer: &Buffer) -> Buffer {
         (self.vtable.clone)(buffer)
     }
 
-    pub unsafe fn drop_buffer(&self, buffer: &mut Buffer) {
+    pub(crate) unsafe fn drop_buffer(&self, buffer: &mut Buffer) {
         (self.vtable.drop)(buffer)
     }
 }
@@ -193,18 +248,51 @@ pub trait Stat: Shareable {
 
     /// Convert from a unique index of the stat.
     ///
-    /// This function can panic in case of a mismatch.
+    /// This function can panic in case of a mismatch,
+    /// unless [`set_lenient_stat_index`] is enabled and the implementation respects it.
     fn from_index(index: u64) -> Self;
 
     /// Register all fields for serialization.
     fn values() -> impl IntoIterator<Item = Self>;
 
+    /// Obtain a type erased [`StatInst`] of this stat.
+    fn to_inst(&self) -> StatInst {
+        self.as_entry()
+    }
+
     /// Check for equality on generic stats.
     fn is<T: Stat>(&self, other: &T) -> bool {
         self.as_entry() == other.as_entry()
     }
 }
 
+/// Name and [`StatVTable`] of one instantiation of a generic [`Stat`],
+/// required by the derive macro on generic structs since a `static` cannot depend on generic parameters.
+///
+/// Implement with [`stat_instance!`](crate::stat_instance) for each instantiation in use.
+pub trait StatInstance: Sized {
+    /// Globally unique name of this instantiation, must be stable since it is used in serialization.
+    const NAME: &'static str;
+
+    /// Return a reference to a static [`StatVTable`], see [`Stat::vtable`].
+    fn vtable() -> &'static StatVTable<Self>;
+}
+
+static LENIENT_STAT_INDEX: AtomicBool = AtomicBool::new(false);
+
+/// If enabled, [`Stat::from_index`] implementations generated by the derive macro
+/// return the `#[default]` variant, or the first variant, on a bad index instead of panicking.
+///
+/// Useful when loading old or foreign data.
+pub fn set_lenient_stat_index(enabled: bool) {
+    LENIENT_STAT_INDEX.store(enabled, atomic::Ordering::Relaxed)
+}
+
+/// Returns true if [`set_lenient_stat_index`] is enabled.
+pub fn lenient_stat_index() -> bool {
+    LENIENT_STAT_INDEX.load(atomic::Ordering::Relaxed)
+}
+
 /// Extension methods to [`Stat`].
 pub(crate) trait StatExt: Stat {
     fn index_to_name(index: u64) -> &'static str {
@@ -283,6 +371,32 @@ pub struct StatValuePair {
     pub(crate) value: Buffer,
 }
 
+/// A [`Stat::Value`] borrowed from a [`StatValuePair`] that memoizes [`StatValue::eval`],
+/// see [`StatValuePair::cached`].
+///
+/// The value can only be mutated through [`CachedEval::value_mut`], which invalidates the cache.
+pub struct CachedEval<'t, T: Stat> {
+    value: &'t mut T::Value,
+    cached: Option<<T::Value as StatValue>::Out>,
+}
+
+impl<T: Stat> CachedEval<'_, T> {
+    /// Evaluate the value, reusing the previous result if the value has not been mutated since.
+    pub fn eval(&mut self) -> <T::Value as StatValue>::Out {
+        self.cached.get_or_insert_with(|| self.value.eval()).clone()
+    }
+
+    pub fn value(&self) -> &T::Value {
+        self.value
+    }
+
+    /// Obtain the value mutably, invalidating the cache.
+    pub fn value_mut(&mut self) -> &mut T::Value {
+        self.cached = None;
+        self.value
+    }
+}
+
 impl Debug for StatValuePair {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("StatValuePair")
@@ -294,6 +408,21 @@ impl Debug for StatValuePair {
     }
 }
 
+/// A type erased description of a [`StatValuePair`], see [`StatValuePair::describe`].
+#[cfg(feature = "json")]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StatDescription {
+    pub name: &'static str,
+    pub value: serde_json::Value,
+}
+
+impl Drop for StatValuePair {
+    fn drop(&mut self) {
+        // Safety: Safe because invariant.
+        unsafe { self.stat.drop_buffer(&mut self.value) };
+    }
+}
+
 impl StatValuePair {
     pub fn new<S: Stat>(stat: &S, value: S::Value) -> Self {
         StatValuePair {
@@ -309,6 +438,24 @@ impl StatValuePair {
         }
     }
 
+    /// Describe the internal state of the value as json, useful for debug consoles.
+    ///
+    /// # Errors
+    ///
+    /// If the value cannot be represented as json.
+    ///
+    /// # Panics
+    ///
+    /// If the stat does not support serialization.
+    #[cfg(feature = "json")]
+    pub fn describe(&self) -> Result<StatDescription, serde_json::Error> {
+        let value = unsafe { (self.stat.vtable.as_serialize)(&self.value) };
+        Ok(StatDescription {
+            name: self.stat.name(),
+            value: serde_json::to_value(value)?,
+        })
+    }
+
     /// Check for equality on generic stats.
     pub fn is<T: Stat>(&self, other: &T) -> bool {
         self.stat == other.as_entry()
@@ -328,6 +475,22 @@ impl StatValuePair {
         }
     }
 
+    /// Cast to a concrete [`Stat::Value`], same as [`StatValuePair::cast`].
+    ///
+    /// In debug builds, asserts the stat reconstructed by [`Stat::from_index`]
+    /// maps back to the original index, catching broken [`Stat`] implementations.
+    pub fn cast_checked<'t, T: Stat>(&mut self) -> Option<(T, &'t mut T::Value)> {
+        let index = self.stat.index;
+        let (stat, value) = self.cast::<T>()?;
+        debug_assert_eq!(
+            stat.as_index(),
+            index,
+            "Stat::from_index of {} does not round trip.",
+            stat.name()
+        );
+        Some((stat, value))
+    }
+
     /// Cast to a concrete [`Stat::Value`].
     pub fn is_then_cast<'t, T: Stat>(&mut self, other: &T) -> Option<&'t mut T::Value> {
         validate::<T>();
@@ -339,11 +502,72 @@ impl StatValuePair {
         }
     }
 
+    /// Reset the value to [`Default::default`], discarding all previous modifiers.
+    ///
+    /// Streams that run after the reset still apply, and this does not
+    /// restore values registered in [`GlobalStatDefaults`](crate::GlobalStatDefaults).
+    pub fn reset_to_default(&mut self) {
+        // Safety: Safe because invariant.
+        unsafe { self.stat.drop_buffer(&mut self.value) };
+        self.value = (self.stat.vtable.default)();
+    }
+
+    /// Replace the value if the stat is of type `T`, discarding all previous modifiers.
+    ///
+    /// Returns `false` if the stat is not of type `T`.
+    /// Streams that run after the reset still apply.
+    pub fn reset_to<T: Stat>(&mut self, value: T::Value) -> bool {
+        match self.cast::<T>() {
+            Some((_, v)) => {
+                *v = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Join the value of `other` into this if both are the same stat,
+    /// e.g. to apply a precomputed buff.
+    ///
+    /// Returns `false` if the stats are different.
+    pub fn join_from(&mut self, other: &StatValuePair) -> bool {
+        if self.stat != other.stat {
+            return false;
+        }
+        let stat = self.stat;
+        // Safety: Safe because invariant, the stats are equal so the values have the same type.
+        unsafe { (stat.vtable.join)(&mut self.value, &other.value) };
+        true
+    }
+
     /// Cast to a concrete [`Stat::Value`].
     pub fn into_result<T: Stat>(self) -> Option<T::Value> {
         validate::<T>();
         if ptr::eq(self.stat.vtable, &T::vtable().vtable) {
-            Some(unsafe { self.value.into() })
+            Some(unsafe { self.into_buffer().into() })
+        } else {
+            None
+        }
+    }
+
+    /// Obtain the inner [`Buffer`] without dropping it.
+    pub(crate) fn into_buffer(self) -> Buffer {
+        let this = ManuallyDrop::new(self);
+        // Safety: `this` is never dropped.
+        unsafe { ptr::read(&this.value) }
+    }
+
+    /// Borrow as a concrete [`Stat::Value`] that memoizes [`StatValue::eval`],
+    /// useful if the value is evaluated multiple times, e.g. by relation streams peeking.
+    ///
+    /// Returns [`None`] if the stat is not of type `T`.
+    pub fn cached<T: Stat>(&mut self) -> Option<CachedEval<'_, T>> {
+        validate::<T>();
+        if ptr::eq(self.stat.vtable, &T::vtable().vtable) {
+            Some(CachedEval {
+                value: unsafe { self.value.as_mut() },
+                cached: None,
+            })
         } else {
             None
         }
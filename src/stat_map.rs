@@ -2,19 +2,24 @@ use crate::operations::StatOperation;
 use crate::stat::StatValuePair;
 use crate::{
     Buffer, Qualifier, QualifierFlag, QualifierQuery, Querier, Stat, StatExt, StatInst, StatStream,
-    StatValue,
+    StatValue, StatVisitor,
 };
 use bevy_ecs::component::Component;
 use bevy_ecs::entity::Entity;
 use bevy_ecs::reflect::ReflectComponent;
-use bevy_reflect::{Reflect, ReflectDeserialize, ReflectSerialize};
-use serde::de::{DeserializeOwned, DeserializeSeed, Visitor};
-use serde::ser::SerializeSeq;
+use bevy_reflect::{
+    ApplyError, DynamicList, DynamicStruct, List, PartialReflect, Reflect, ReflectDeserialize,
+    ReflectFromPtr, ReflectSerialize, TypeRegistry,
+};
+use serde::de::{DeserializeOwned, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeSeq, SerializeStruct, SerializeTuple};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::fmt::Debug;
 use std::marker::PhantomData;
-use std::mem;
+use std::mem::{self, ManuallyDrop};
+use std::ptr;
 
 pub(crate) struct StatMapEntry<Q: QualifierFlag> {
     stat: StatInst,
@@ -47,6 +52,19 @@ impl<Q: QualifierFlag> StatMapEntry<Q> {
         mem::forget(self);
         result
     }
+
+    fn into_pair(self) -> (Qualifier<Q>, StatValuePair) {
+        let this = ManuallyDrop::new(self);
+        // Safety: `this` is never dropped.
+        let (qualifier, value) = unsafe { (ptr::read(&this.qualifier), ptr::read(&this.buffer)) };
+        (
+            qualifier,
+            StatValuePair {
+                stat: this.stat,
+                value,
+            },
+        )
+    }
 }
 
 /// A type erased storage component of qualified stats.
@@ -61,11 +79,19 @@ impl<Q: QualifierFlag> StatMapEntry<Q> {
 /// # Serialization
 ///
 /// Deserialization must be done inside a [`bevy_serde_lens_core`] deserialize scope.
+///
+/// Entries are written in order of stat name then qualifier,
+/// so equal maps serialize identically across runs.
 #[derive(Component, Serialize, Deserialize, Reflect, Clone)]
 #[reflect(Component, Serialize, Deserialize)]
 #[reflect(where Q: Serialize + DeserializeOwned)]
 pub struct StatMap<Q: QualifierFlag> {
     #[reflect(ignore)]
+    #[serde(
+        serialize_with = "serialize_stable",
+        deserialize_with = "deserialize_sorted"
+    )]
+    #[serde(bound(serialize = "Q: Serialize", deserialize = "Q: Deserialize<'de>"))]
     inner: Vec<StatMapEntry<Q>>,
 }
 
@@ -98,6 +124,31 @@ fn sort<Q: QualifierFlag>(a: &StatMapEntry<Q>, b: &StatMapEntry<Q>) -> Ordering
     a.stat.cmp(&b.stat).then(a.qualifier.cmp(&b.qualifier))
 }
 
+/// Order entries by stat name then qualifier, unlike [`sort`]
+/// this does not depend on vtable addresses so is stable across runs.
+fn stable_order<'t, Q: QualifierFlag>(
+    entries: impl IntoIterator<Item = &'t StatMapEntry<Q>>,
+) -> Vec<&'t StatMapEntry<Q>> {
+    let mut entries: Vec<_> = entries.into_iter().collect();
+    entries.sort_by(|a, b| (a.stat.name(), &a.qualifier).cmp(&(b.stat.name(), &b.qualifier)));
+    entries
+}
+
+fn serialize_stable<Q: QualifierFlag + Serialize, S: Serializer>(
+    inner: &[StatMapEntry<Q>],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    stable_order(inner).serialize(serializer)
+}
+
+fn deserialize_sorted<'de, Q: QualifierFlag + Deserialize<'de>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<StatMapEntry<Q>>, D::Error> {
+    let mut inner = Vec::<StatMapEntry<Q>>::deserialize(deserializer)?;
+    inner.sort_by(sort);
+    Ok(inner)
+}
+
 impl<Q: QualifierFlag, S: Stat> FromIterator<(Qualifier<Q>, S, S::Value)> for StatMap<Q> {
     fn from_iter<T: IntoIterator<Item = (Qualifier<Q>, S, S::Value)>>(iter: T) -> Self {
         let mut inner: Vec<_> = iter
@@ -116,6 +167,8 @@ impl<Q: QualifierFlag, S: Stat> FromIterator<(Qualifier<Q>, S, S::Value)> for St
     }
 }
 
+/// Values sharing the same qualifier and stat are joined, with existing values joined first,
+/// see [`StatMap::coalesce`].
 impl<Q: QualifierFlag, S: Stat> Extend<(Qualifier<Q>, S, S::Value)> for StatMap<Q> {
     fn extend<T: IntoIterator<Item = (Qualifier<Q>, S, S::Value)>>(&mut self, iter: T) {
         self.inner
@@ -127,7 +180,7 @@ impl<Q: QualifierFlag, S: Stat> Extend<(Qualifier<Q>, S, S::Value)> for StatMap<
                     buffer: Buffer::from(value),
                 }
             }));
-        self.inner.sort_by(sort);
+        self.coalesce();
     }
 }
 
@@ -136,6 +189,38 @@ impl<Q: QualifierFlag> StatMap<Q> {
         Self { inner: Vec::new() }
     }
 
+    /// Create an empty [`StatMap`] with space for at least `capacity` entries.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Create a [`StatMap`] from entries already ordered by stat index then qualifier,
+    /// skipping the sort done in [`FromIterator`].
+    ///
+    /// Unsorted input causes lookups to fail but is otherwise safe.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, if the input is not sorted.
+    pub fn from_sorted_unchecked<S: Stat>(
+        iter: impl IntoIterator<Item = (Qualifier<Q>, S, S::Value)>,
+    ) -> Self {
+        let iter = iter.into_iter();
+        let mut inner = Vec::with_capacity(iter.size_hint().0);
+        inner.extend(iter.map(|(qualifier, stat, value)| StatMapEntry {
+            stat: stat.as_entry(),
+            qualifier,
+            buffer: Buffer::from(value),
+        }));
+        debug_assert!(
+            inner.windows(2).all(|x| sort(&x[0], &x[1]).is_le()),
+            "StatMap::from_sorted_unchecked: input is not sorted."
+        );
+        StatMap { inner }
+    }
+
     /// Drops all items in the map.
     pub fn clear(&mut self) {
         self.inner.clear()
@@ -151,6 +236,137 @@ impl<Q: QualifierFlag> StatMap<Q> {
         self.inner.len()
     }
 
+    /// Returns the number of elements the map can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Reserves space for at least `additional` more elements.
+    pub fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional)
+    }
+
+    /// Shrinks the capacity of the map as much as possible.
+    pub fn shrink_to_fit(&mut self) {
+        self.inner.shrink_to_fit()
+    }
+
+    /// Joins entries sharing the same qualifier and stat into one.
+    ///
+    /// Maps built with [`FromIterator`] may contain duplicated keys,
+    /// this compacts them without changing query results.
+    pub fn coalesce(&mut self) {
+        self.inner.sort_by(sort);
+        self.inner.dedup_by(|duplicate, entry| {
+            if entry.stat != duplicate.stat || entry.qualifier != duplicate.qualifier {
+                return false;
+            }
+            unsafe { (entry.stat.vtable.join)(&mut entry.buffer, &duplicate.buffer) };
+            true
+        });
+    }
+
+    /// Joins all entries of `other` into this map.
+    ///
+    /// Entries sharing the same qualifier and stat are joined via [`StatValue::join`],
+    /// other entries are cloned into the map.
+    pub fn join_from(&mut self, other: &StatMap<Q>) {
+        for entry in &other.inner {
+            match self.binary_search(&entry.qualifier, &entry.stat) {
+                Ok(at) => unsafe {
+                    (entry.stat.vtable.join)(&mut self.inner[at].buffer, &entry.buffer)
+                },
+                Err(at) => self.inner.insert(at, entry.clone()),
+            }
+        }
+    }
+
+    /// Capture the current entries of the map, restorable via [`StatMap::rollback`].
+    ///
+    /// Useful for speculative simulation, e.g. applying buffs during AI planning then reverting.
+    ///
+    /// Clones every entry, prefer [`StatMapRecorder`] to undo a few edits on a large map.
+    pub fn checkpoint(&self) -> StatMapCheckpoint<Q> {
+        StatMapCheckpoint {
+            inner: self.inner.clone(),
+        }
+    }
+
+    /// Restore the map to the state captured by [`StatMap::checkpoint`].
+    ///
+    /// Entries are moved out of the checkpoint, not cloned.
+    pub fn rollback(&mut self, checkpoint: StatMapCheckpoint<Q>) {
+        self.inner = checkpoint.inner;
+    }
+
+    /// Converts the map into a list of type erased entries, sorted by stat and qualifier.
+    ///
+    /// Values are moved, not cloned.
+    pub fn into_entries(self) -> Vec<(Qualifier<Q>, StatValuePair)> {
+        self.inner
+            .into_iter()
+            .map(StatMapEntry::into_pair)
+            .collect()
+    }
+
+    /// Moves all entries out of the map as type erased [`StatValuePair`]s, leaving it empty.
+    ///
+    /// Values are moved, not cloned, and can be reinserted via [`StatMap::insert_raw`].
+    pub fn drain(&mut self) -> impl Iterator<Item = (Qualifier<Q>, StatValuePair)> + '_ {
+        self.inner.drain(..).map(StatMapEntry::into_pair)
+    }
+
+    /// Creates a map from a list of type erased entries, inverse of [`StatMap::into_entries`].
+    ///
+    /// Duplicated keys are kept, see [`StatMap::coalesce`].
+    pub fn from_entries(entries: impl IntoIterator<Item = (Qualifier<Q>, StatValuePair)>) -> Self {
+        let mut inner: Vec<_> = entries
+            .into_iter()
+            .map(|(qualifier, pair)| StatMapEntry {
+                stat: pair.stat,
+                qualifier,
+                buffer: pair.into_buffer(),
+            })
+            .collect();
+        inner.sort_by(sort);
+        StatMap { inner }
+    }
+
+    /// Obtain a reflected view of all entries, allowing editors to mutate values in place.
+    ///
+    /// Values whose types are not registered in the [`TypeRegistry`] are `None`.
+    pub fn reflect_entries(&mut self, registry: &TypeRegistry) -> ReflectStatMap<'_, Q> {
+        let entries = self
+            .inner
+            .iter_mut()
+            .map(|entry| {
+                let value = registry
+                    .get_type_data::<ReflectFromPtr>((entry.stat.vtable.value_type_id)())
+                    .map(|from_ptr| {
+                        // Safety: type id of `ReflectFromPtr` is the type id of the value.
+                        unsafe { from_ptr.as_reflect_mut(entry.buffer.as_ptr_mut()) }
+                    });
+                ReflectStatEntry {
+                    qualifier: &entry.qualifier,
+                    name: entry.stat.name(),
+                    value,
+                }
+            })
+            .collect();
+        ReflectStatMap { entries }
+    }
+
+    /// Visit all entries with a [`StatVisitor`].
+    pub fn visit(&self, visitor: &mut dyn StatVisitor<Q>) {
+        for entry in &self.inner {
+            unsafe {
+                (entry.stat.vtable.visit_eval)(&entry.buffer, &mut |value| {
+                    visitor.visit(&entry.qualifier, entry.stat, value)
+                })
+            };
+        }
+    }
+
     /// Performs a binary search for a value.
     fn binary_search(&self, qualifier: &Qualifier<Q>, stat: &StatInst) -> Result<usize, usize> {
         self.inner.binary_search_by(
@@ -179,6 +395,58 @@ impl<Q: QualifierFlag> StatMap<Q> {
         };
     }
 
+    /// Inserts a type erased [`StatValuePair`], replacing the existing value.
+    pub fn insert_raw(&mut self, qualifier: Qualifier<Q>, pair: StatValuePair) {
+        let entry = StatMapEntry {
+            stat: pair.stat,
+            qualifier,
+            buffer: pair.into_buffer(),
+        };
+        match self.binary_search(&entry.qualifier, &entry.stat) {
+            Ok(at) => self.inner[at] = entry,
+            Err(at) => self.inner.insert(at, entry),
+        }
+    }
+
+    /// Obtain an entry for in place insertion or modification with a single lookup.
+    pub fn entry<S: Stat>(&mut self, qualifier: Qualifier<Q>, stat: S) -> StatEntry<'_, Q, S> {
+        let stat = stat.as_entry();
+        let at = self.binary_search(&qualifier, &stat);
+        StatEntry {
+            map: self,
+            qualifier,
+            stat,
+            at,
+            p: PhantomData,
+        }
+    }
+
+    /// Inserts multiple [`Stat::Value`]s in their component form, sorting only once.
+    ///
+    /// Like [`StatMap::insert`], the last value inserted with a key wins.
+    pub fn insert_many<S: Stat>(
+        &mut self,
+        iter: impl IntoIterator<Item = (Qualifier<Q>, S, S::Value)>,
+    ) {
+        self.inner.extend(
+            iter.into_iter()
+                .map(|(qualifier, stat, value)| StatMapEntry {
+                    stat: stat.as_entry(),
+                    qualifier,
+                    buffer: Buffer::from(value),
+                }),
+        );
+        // Stable sort preserves insertion order among duplicates.
+        self.inner.sort_by(sort);
+        self.inner.dedup_by(|duplicate, entry| {
+            if entry.stat != duplicate.stat || entry.qualifier != duplicate.qualifier {
+                return false;
+            }
+            mem::swap(&mut entry.buffer, &mut duplicate.buffer);
+            true
+        });
+    }
+
     /// Inserts a [`Stat::Value`] in its evaluated form.
     pub fn insert_base<S: Stat>(
         &mut self,
@@ -223,6 +491,25 @@ impl<Q: QualifierFlag> StatMap<Q> {
         }
     }
 
+    /// Obtains multiple mutable [`Stat::Value`]s at once.
+    ///
+    /// Returns [`None`] if any two keys are the same.
+    pub fn get_many_mut<S: Stat, const N: usize>(
+        &mut self,
+        keys: [(&Qualifier<Q>, &S); N],
+    ) -> Option<[Option<&mut S::Value>; N]> {
+        let keys = keys.map(|(qualifier, stat)| (qualifier, stat.as_entry()));
+        for i in 0..N {
+            if keys[..i].contains(&keys[i]) {
+                return None;
+            }
+        }
+        let indices = keys.map(|(qualifier, stat)| self.binary_search(qualifier, &stat).ok());
+        let ptr = self.inner.as_mut_ptr();
+        // Safety: keys are unique so indices are unique.
+        Some(indices.map(|at| at.map(|at| unsafe { (*ptr.add(at)).buffer.as_mut() })))
+    }
+
     /// Removes and obtains a [`Stat::Value`].
     pub fn remove<S: Stat>(&mut self, qualifier: &Qualifier<Q>, stat: &S) -> Option<S::Value> {
         let stat = stat.as_entry();
@@ -232,6 +519,27 @@ impl<Q: QualifierFlag> StatMap<Q> {
         }
     }
 
+    /// Returns true if the map contains a value for the qualifier and stat.
+    pub fn contains<S: Stat>(&self, qualifier: &Qualifier<Q>, stat: &S) -> bool {
+        self.binary_search(qualifier, &stat.as_entry()).is_ok()
+    }
+
+    /// Returns the number of entries of a particular stat across all qualifiers.
+    pub fn count<S: Stat>(&self, stat: &S) -> usize {
+        self.slice(stat.as_entry()).len()
+    }
+
+    /// Obtains the base a [`Stat::Value`] was inserted with, see [`StatValue::into_base`].
+    ///
+    /// Only meaningful for entries inserted via [`StatMap::insert_base`] and not modified since.
+    pub fn get_base<S: Stat>(
+        &self,
+        qualifier: &Qualifier<Q>,
+        stat: &S,
+    ) -> Option<<S::Value as StatValue>::Base> {
+        self.get(qualifier, stat)?.clone().into_base()
+    }
+
     /// Obtains a [`Stat::Value`] in its evaluated form.
     pub fn get_evaled<S: Stat>(
         &self,
@@ -245,6 +553,29 @@ impl<Q: QualifierFlag> StatMap<Q> {
         }
     }
 
+    /// Obtains a [`Stat::Value`] in its evaluated form, or `default` if not found.
+    ///
+    /// Unlike [`StatMap::eval_stat`], this does not aggregate other qualifiers.
+    pub fn get_evaled_or<S: Stat>(
+        &self,
+        qualifier: &Qualifier<Q>,
+        stat: &S,
+        default: <S::Value as StatValue>::Out,
+    ) -> <S::Value as StatValue>::Out {
+        self.get_evaled(qualifier, stat).unwrap_or(default)
+    }
+
+    /// Obtains a [`Stat::Value`] in its evaluated form, or the default `Out` if not found.
+    ///
+    /// Unlike [`StatMap::eval_stat`], this does not aggregate other qualifiers.
+    pub fn get_evaled_or_default<S: Stat>(
+        &self,
+        qualifier: &Qualifier<Q>,
+        stat: &S,
+    ) -> <S::Value as StatValue>::Out {
+        self.get_evaled(qualifier, stat).unwrap_or_default()
+    }
+
     /// Iterate over a particular stat.
     pub(crate) fn slice(&self, stat: StatInst) -> &[StatMapEntry<Q>] {
         let fst = self.inner.partition_point(|x| x.stat < stat);
@@ -278,6 +609,20 @@ impl<Q: QualifierFlag> StatMap<Q> {
             .map(|x| (&x.qualifier, unsafe { x.buffer.as_mut() }))
     }
 
+    /// Iterate over all entries regardless of stat type.
+    pub fn iter_all(&self) -> impl Iterator<Item = (&Qualifier<Q>, &'static str, &dyn Debug)> {
+        self.inner.iter().map(|x| {
+            (&x.qualifier, x.stat.name(), unsafe {
+                (x.stat.vtable.as_debug)(&x.buffer)
+            })
+        })
+    }
+
+    /// Iterate over the keys of all entries, values can be obtained with [`StatInst::is`].
+    pub fn iter_entries(&self) -> impl Iterator<Item = (&Qualifier<Q>, StatInst)> {
+        self.inner.iter().map(|x| (&x.qualifier, x.stat))
+    }
+
     /// Remove all instances of a given stat.
     pub fn remove_all<S: Stat>(&mut self, stat: &S) {
         let stat = stat.as_entry();
@@ -286,6 +631,51 @@ impl<Q: QualifierFlag> StatMap<Q> {
         self.inner.drain(fst..snd);
     }
 
+    /// Shift the base value of a stat after its default changed, see [`StatValue::rebase`].
+    ///
+    /// Only the entry of [`Qualifier::none`] is rebased, since it is the only one
+    /// contributing to every query, qualified entries are modifiers on top of it.
+    ///
+    /// Useful for keeping saved values consistent after a balance change.
+    pub fn rebase<S: Stat>(&mut self, stat: &S, old_default: &S::Value, new_default: &S::Value) {
+        if let Some(value) = self.get_mut(&Qualifier::none(), stat) {
+            value.rebase(old_default, new_default);
+        }
+    }
+
+    /// Retain only entries matching a predicate, order is preserved.
+    pub fn retain(&mut self, mut f: impl FnMut(&Qualifier<Q>, StatInst) -> bool) {
+        self.inner.retain(|entry| f(&entry.qualifier, entry.stat))
+    }
+
+    /// Retain only instances of a given stat matching a predicate,
+    /// the predicate may also modify the value. Other stats are not affected.
+    pub fn retain_mut<S: Stat>(
+        &mut self,
+        stat: &S,
+        mut f: impl FnMut(&Qualifier<Q>, &mut S::Value) -> bool,
+    ) {
+        let stat = stat.as_entry();
+        self.inner.retain_mut(|entry| {
+            entry.stat != stat || f(&entry.qualifier, unsafe { entry.buffer.as_mut() })
+        })
+    }
+
+    /// Remove all instances of stats whose [`StatInst::name`] matches a predicate.
+    ///
+    /// The predicate is called once per distinct stat.
+    pub fn remove_by_name(&mut self, predicate: impl Fn(&str) -> bool) {
+        let mut last: Option<(StatInst, bool)> = None;
+        self.inner.retain(|entry| match last {
+            Some((stat, remove)) if stat == entry.stat => !remove,
+            _ => {
+                let remove = predicate(entry.stat.name());
+                last = Some((entry.stat, remove));
+                !remove
+            }
+        });
+    }
+
     /// Create or modify a stat via a [`StatOperation`].
     ///
     /// Create a [`Default`] stat if non-existent.
@@ -312,6 +702,33 @@ impl<Q: QualifierFlag> StatMap<Q> {
         }
     }
 
+    /// Create or modify a stat via a serialized [`StatOperation`].
+    ///
+    /// Create a [`Default`] stat if non-existent.
+    #[cfg(feature = "asset")]
+    pub(crate) fn modify_deserialize(
+        &mut self,
+        qualifier: Qualifier<Q>,
+        stat: StatInst,
+        deserializer: &mut dyn erased_serde::Deserializer,
+    ) -> erased_serde::Result<()> {
+        let at = match self.binary_search(&qualifier, &stat) {
+            Ok(at) => at,
+            Err(at) => {
+                self.inner.insert(
+                    at,
+                    StatMapEntry {
+                        stat,
+                        qualifier,
+                        buffer: (stat.vtable.default)(),
+                    },
+                );
+                at
+            }
+        };
+        unsafe { (stat.vtable.deserialize_operation)(&mut self.inner[at].buffer, deserializer) }
+    }
+
     /// Create or modify a stat via a closure.
     ///
     /// Create a [`Default`] stat if non-existent.
@@ -340,10 +757,77 @@ impl<Q: QualifierFlag> StatMap<Q> {
         }
     }
 
+    /// Clone an entry by its key.
+    fn get_entry(&self, qualifier: &Qualifier<Q>, stat: StatInst) -> Option<StatMapEntry<Q>> {
+        match self.binary_search(qualifier, &stat) {
+            Ok(at) => Some(self.inner[at].clone()),
+            Err(_) => None,
+        }
+    }
+
+    /// Replace or remove an entry by its key.
+    fn replace_entry(
+        &mut self,
+        qualifier: &Qualifier<Q>,
+        stat: StatInst,
+        entry: Option<StatMapEntry<Q>>,
+    ) {
+        match (self.binary_search(qualifier, &stat), entry) {
+            (Ok(at), Some(entry)) => self.inner[at] = entry,
+            (Ok(at), None) => drop(self.inner.remove(at)),
+            (Err(at), Some(entry)) => self.inner.insert(at, entry),
+            (Err(_), None) => (),
+        }
+    }
+
     pub fn query_stat<S: Stat>(&self, qualifier: &QualifierQuery<Q>, stat: &S) -> S::Value {
         let mut stat = StatValuePair::new_default(stat);
         self.stream_stat(Entity::PLACEHOLDER, qualifier, &mut stat, Querier::noop());
-        unsafe { stat.value.into::<S::Value>() }
+        unsafe { stat.into_buffer().into::<S::Value>() }
+    }
+
+    /// Same as [`StatMap::query_stat`], also returns qualifiers of all joined entries.
+    pub fn query_stat_explained<S: Stat>(
+        &self,
+        qualifier: &QualifierQuery<Q>,
+        stat: &S,
+    ) -> (S::Value, Vec<Qualifier<Q>>) {
+        let mut value = S::Value::default();
+        let mut qualifiers = Vec::new();
+        for entry in self.slice(stat.as_entry()) {
+            if entry.qualifier.qualifies_as(qualifier) {
+                value.join_by_ref(unsafe { entry.buffer.as_ref() });
+                qualifiers.push(entry.qualifier.clone());
+            }
+        }
+        (value, qualifiers)
+    }
+
+    /// Evaluate every stat with at least one entry qualifying for `qualifier`,
+    /// returning the name and the [`Debug`] form of [`StatValue::Out`] per stat.
+    ///
+    /// Useful for inspectors that do not know the concrete [`Stat`] types in the map.
+    pub fn eval_all(&self, qualifier: &QualifierQuery<Q>) -> Vec<(&'static str, Box<dyn Debug>)> {
+        let mut result = Vec::new();
+        for entries in self.inner.chunk_by(|a, b| a.stat == b.stat) {
+            let stat = entries[0].stat;
+            let mut value = None;
+            for entry in entries {
+                if entry.qualifier.qualifies_as(qualifier) {
+                    let value = value.get_or_insert_with(|| StatValuePair {
+                        stat,
+                        value: (stat.vtable.default)(),
+                    });
+                    unsafe { (stat.vtable.join)(&mut value.value, &entry.buffer) };
+                }
+            }
+            if let Some(value) = value {
+                result.push((stat.name(), unsafe {
+                    (stat.vtable.eval_debug)(&value.value)
+                }));
+            }
+        }
+        result
     }
 
     pub fn eval_stat<S: Stat>(
@@ -351,7 +835,7 @@ impl<Q: QualifierFlag> StatMap<Q> {
         qualifier: &QualifierQuery<Q>,
         stat: &S,
     ) -> <S::Value as StatValue>::Out {
-        self.query_stat(qualifier, stat).eval()
+        self.query_stat(qualifier, stat).finish()
     }
 }
 
@@ -372,6 +856,10 @@ impl<Q: QualifierFlag> StatStream for StatMap<Q> {
             }
         }
     }
+
+    fn applies_to(&self, stat: StatInst) -> bool {
+        !self.slice(stat).is_empty()
+    }
 }
 
 impl<Q: QualifierFlag + Serialize> Serialize for StatMapEntry<Q> {
@@ -379,7 +867,19 @@ impl<Q: QualifierFlag + Serialize> Serialize for StatMapEntry<Q> {
         let mut seq = serializer.serialize_seq(Some(3))?;
         seq.serialize_element(&self.qualifier)?;
         seq.serialize_element(&self.stat.name())?;
-        seq.serialize_element(unsafe { &(self.stat.vtable.as_serialize)(&self.buffer) })?;
+        match unsafe { (self.stat.vtable.canonicalize)(&self.buffer) } {
+            Some(buffer) => {
+                // Dropped as a pair to drop the buffer.
+                let value = StatValuePair {
+                    stat: self.stat,
+                    value: buffer,
+                };
+                seq.serialize_element(unsafe { (self.stat.vtable.as_serialize)(&value.value) })?;
+            }
+            None => {
+                seq.serialize_element(unsafe { (self.stat.vtable.as_serialize)(&self.buffer) })?;
+            }
+        }
         seq.end()
     }
 }
@@ -396,6 +896,94 @@ impl<'de, Q: QualifierFlag + Deserialize<'de>> Deserialize<'de> for StatMapEntry
     }
 }
 
+impl<Q: QualifierFlag> StatMap<Q> {
+    /// Current version written by [`StatMap::serialize_versioned`].
+    pub const VERSION: u32 = 1;
+
+    /// Serialize with a version tag, as a tuple `(VERSION, [entries])`.
+    ///
+    /// Use [`StatMap::deserialize_versioned`] to read the result.
+    pub fn serialize_versioned<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        Q: Serialize,
+    {
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&Self::VERSION)?;
+        tuple.serialize_element(&stable_order(&self.inner))?;
+        tuple.end()
+    }
+
+    /// Serialize only entries whose stat matches `predicate`,
+    /// e.g. to persist permanent stats and drop transient buffs.
+    ///
+    /// The output has the same layout as [`StatMap`]'s [`Serialize`] implementation.
+    pub fn serialize_filtered<S: Serializer>(
+        &self,
+        serializer: S,
+        predicate: impl Fn(StatInst) -> bool,
+    ) -> Result<S::Ok, S::Error>
+    where
+        Q: Serialize,
+    {
+        let entries = stable_order(self.inner.iter().filter(|x| predicate(x.stat)));
+        let mut state = serializer.serialize_struct("StatMap", 1)?;
+        state.serialize_field("inner", &entries)?;
+        state.end()
+    }
+
+    /// Deserialize the output of [`StatMap::serialize_versioned`], upgrading older layouts.
+    ///
+    /// Version `0` is the unversioned layout written by [`StatMap`]'s [`Serialize`] implementation.
+    ///
+    /// This requires a self describing format and must be done
+    /// inside a [`bevy_serde_lens_core`] deserialize scope.
+    pub fn deserialize_versioned<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        Q: Deserialize<'de>,
+    {
+        deserializer.deserialize_any(VersionedVisitor::<Q>(PhantomData))
+    }
+}
+
+struct VersionedVisitor<Q: QualifierFlag>(PhantomData<Q>);
+
+impl<'de, Q: QualifierFlag + Deserialize<'de>> Visitor<'de> for VersionedVisitor<Q> {
+    type Value = StatMap<Q>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a versioned stat map")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let Some(version) = seq.next_element::<u32>()? else {
+            return Err(serde::de::Error::custom("Expected version."));
+        };
+        let mut inner = match version {
+            1 => seq.next_element::<Vec<StatMapEntry<Q>>>()?,
+            _ => {
+                return Err(serde::de::Error::custom(format!(
+                    "Unsupported StatMap version {version}."
+                )))
+            }
+        }
+        .ok_or_else(|| serde::de::Error::custom("Expected entries."))?;
+        inner.sort_by(sort);
+        Ok(StatMap { inner })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        // Version 0, the unversioned layout.
+        let Some("inner") = map.next_key::<Cow<str>>()?.as_deref() else {
+            return Err(serde::de::Error::custom("Expected inner."));
+        };
+        let mut inner = map.next_value::<Vec<StatMapEntry<Q>>>()?;
+        inner.sort_by(sort);
+        Ok(StatMap { inner })
+    }
+}
+
 pub struct TupleSeed<Q: QualifierFlag>(PhantomData<Q>);
 
 pub struct DynSeed<Q: QualifierFlag> {
@@ -450,3 +1038,287 @@ impl<'de, Q: QualifierFlag> DeserializeSeed<'de> for DynSeed<Q> {
         (self.f)(deserializer).map_err(serde::de::Error::custom)
     }
 }
+
+/// A reversible edit on a single key of a [`StatMap`].
+struct StatMapEdit<Q: QualifierFlag> {
+    qualifier: Qualifier<Q>,
+    stat: StatInst,
+    before: Option<StatMapEntry<Q>>,
+    after: Option<StatMapEntry<Q>>,
+}
+
+impl<Q: QualifierFlag> Clone for StatMapEdit<Q> {
+    fn clone(&self) -> Self {
+        Self {
+            qualifier: self.qualifier.clone(),
+            stat: self.stat,
+            before: self.before.clone(),
+            after: self.after.clone(),
+        }
+    }
+}
+
+/// A reflected view of a [`StatMap`], see [`StatMap::reflect_entries`].
+///
+/// Entries can be edited in place, or through [`ReflectStatMap::to_dynamic`]
+/// and [`ReflectStatMap::apply`] for editors like `bevy-inspector-egui` that walk a [`List`].
+#[derive(Debug)]
+pub struct ReflectStatMap<'t, Q: QualifierFlag> {
+    pub entries: Vec<ReflectStatEntry<'t, Q>>,
+}
+
+impl<Q: QualifierFlag> ReflectStatMap<'_, Q> {
+    /// Create a [`DynamicList`] of [`DynamicStruct`]s with fields `qualifier`, `name` and `value`.
+    ///
+    /// Field `value` is missing if its type is not registered.
+    pub fn to_dynamic(&self) -> DynamicList
+    where
+        Qualifier<Q>: PartialReflect,
+    {
+        let mut list = DynamicList::default();
+        for entry in &self.entries {
+            let mut item = DynamicStruct::default();
+            item.insert("qualifier", entry.qualifier.clone());
+            item.insert("name", entry.name.to_owned());
+            if let Some(value) = &entry.value {
+                item.insert_boxed("value", value.clone_value());
+            }
+            list.push(item);
+        }
+        list
+    }
+
+    /// Write field `value` of each item in a list created by [`ReflectStatMap::to_dynamic`] back to the [`StatMap`].
+    pub fn apply(&mut self, list: &dyn List) -> Result<(), ApplyError> {
+        if list.len() != self.entries.len() {
+            return Err(ApplyError::DifferentSize {
+                from_size: list.len(),
+                to_size: self.entries.len(),
+            });
+        }
+        for (entry, item) in self.entries.iter_mut().zip(list.iter()) {
+            let item = item.reflect_ref().as_struct()?;
+            if let (Some(value), Some(field)) = (&mut entry.value, item.field("value")) {
+                value.try_apply(field)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An entry in a [`ReflectStatMap`].
+#[derive(Debug)]
+pub struct ReflectStatEntry<'t, Q: QualifierFlag> {
+    pub qualifier: &'t Qualifier<Q>,
+    pub name: &'static str,
+    pub value: Option<&'t mut dyn Reflect>,
+}
+
+/// A view into a single key of a [`StatMap`], see [`StatMap::entry`].
+pub struct StatEntry<'t, Q: QualifierFlag, S: Stat> {
+    map: &'t mut StatMap<Q>,
+    qualifier: Qualifier<Q>,
+    stat: StatInst,
+    at: Result<usize, usize>,
+    p: PhantomData<S>,
+}
+
+impl<'t, Q: QualifierFlag, S: Stat> StatEntry<'t, Q, S> {
+    /// Insert `value` if vacant, returns the stored value.
+    pub fn or_insert(self, value: S::Value) -> &'t mut S::Value {
+        self.or_insert_with(|| value)
+    }
+
+    /// Insert the result of `f` if vacant, returns the stored value.
+    pub fn or_insert_with(self, f: impl FnOnce() -> S::Value) -> &'t mut S::Value {
+        let at = match self.at {
+            Ok(at) => at,
+            Err(at) => {
+                self.map.inner.insert(
+                    at,
+                    StatMapEntry {
+                        stat: self.stat,
+                        qualifier: self.qualifier,
+                        buffer: Buffer::from(f()),
+                    },
+                );
+                at
+            }
+        };
+        unsafe { self.map.inner[at].buffer.as_mut() }
+    }
+
+    /// Insert [`Default::default`] if vacant, returns the stored value.
+    pub fn or_default(self) -> &'t mut S::Value {
+        self.or_insert_with(Default::default)
+    }
+
+    /// Modify the stored value if occupied.
+    pub fn and_modify(self, f: impl FnOnce(&mut S::Value)) -> Self {
+        if let Ok(at) = self.at {
+            f(unsafe { self.map.inner[at].buffer.as_mut() })
+        }
+        self
+    }
+
+    /// Returns true if the key is present.
+    pub fn is_occupied(&self) -> bool {
+        self.at.is_ok()
+    }
+}
+
+impl<Q: QualifierFlag, S: Stat> Debug for StatEntry<'_, Q, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StatEntry")
+            .field("qualifier", &self.qualifier)
+            .field("stat", &self.stat.name())
+            .field("occupied", &self.is_occupied())
+            .finish()
+    }
+}
+
+/// A snapshot of a [`StatMap`], see [`StatMap::checkpoint`].
+#[derive(Clone)]
+pub struct StatMapCheckpoint<Q: QualifierFlag> {
+    inner: Vec<StatMapEntry<Q>>,
+}
+
+impl<Q: QualifierFlag> Debug for StatMapCheckpoint<Q> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StatMapCheckpoint")
+            .field("len", &self.inner.len())
+            .finish()
+    }
+}
+
+/// A wrapper around [`StatMap`] that records edits for undo and redo.
+///
+/// Each edit clones the affected entry before and after the mutation,
+/// so this is intended for editors and tools, not per frame gameplay code.
+#[derive(Clone)]
+pub struct StatMapRecorder<Q: QualifierFlag> {
+    map: StatMap<Q>,
+    undo: Vec<StatMapEdit<Q>>,
+    redo: Vec<StatMapEdit<Q>>,
+}
+
+impl<Q: QualifierFlag> Debug for StatMapRecorder<Q> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StatMapRecorder")
+            .field("map", &self.map)
+            .field("undo", &self.undo.len())
+            .field("redo", &self.redo.len())
+            .finish()
+    }
+}
+
+impl<Q: QualifierFlag> Default for StatMapRecorder<Q> {
+    fn default() -> Self {
+        Self::new(StatMap::new())
+    }
+}
+
+impl<Q: QualifierFlag> StatMapRecorder<Q> {
+    pub fn new(map: StatMap<Q>) -> Self {
+        Self {
+            map,
+            undo: Vec::new(),
+            redo: Vec::new(),
+        }
+    }
+
+    /// Obtain the underlying [`StatMap`].
+    pub fn map(&self) -> &StatMap<Q> {
+        &self.map
+    }
+
+    /// Obtain the underlying [`StatMap`], discarding the history.
+    pub fn into_inner(self) -> StatMap<Q> {
+        self.map
+    }
+
+    /// Returns true if [`StatMapRecorder::undo`] can be called.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns true if [`StatMapRecorder::redo`] can be called.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Drops all recorded edits.
+    pub fn clear_history(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    fn record<T>(
+        &mut self,
+        qualifier: Qualifier<Q>,
+        stat: StatInst,
+        f: impl FnOnce(&mut StatMap<Q>) -> T,
+    ) -> T {
+        let before = self.map.get_entry(&qualifier, stat);
+        let result = f(&mut self.map);
+        let after = self.map.get_entry(&qualifier, stat);
+        self.undo.push(StatMapEdit {
+            qualifier,
+            stat,
+            before,
+            after,
+        });
+        self.redo.clear();
+        result
+    }
+
+    /// Inserts a [`Stat::Value`] in its component form, see [`StatMap::insert`].
+    pub fn insert<S: Stat>(&mut self, qualifier: Qualifier<Q>, stat: S, value: S::Value) {
+        let key = qualifier.clone();
+        self.record(key, stat.as_entry(), |map| {
+            map.insert(qualifier, stat, value)
+        })
+    }
+
+    /// Create or modify a stat via a [`StatOperation`], see [`StatMap::modify`].
+    pub fn modify<S: Stat>(
+        &mut self,
+        qualifier: Qualifier<Q>,
+        stat: S,
+        value: StatOperation<S::Value>,
+    ) {
+        let key = qualifier.clone();
+        self.record(key, stat.as_entry(), |map| {
+            map.modify(qualifier, stat, value)
+        })
+    }
+
+    /// Removes and obtains a [`Stat::Value`], see [`StatMap::remove`].
+    pub fn remove<S: Stat>(&mut self, qualifier: &Qualifier<Q>, stat: &S) -> Option<S::Value> {
+        self.record(qualifier.clone(), stat.as_entry(), |map| {
+            map.remove(qualifier, stat)
+        })
+    }
+
+    /// Revert the last edit, returns false if there is nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let Some(edit) = self.undo.pop() else {
+            return false;
+        };
+        self.map
+            .replace_entry(&edit.qualifier, edit.stat, edit.before.clone());
+        self.redo.push(edit);
+        true
+    }
+
+    /// Reapply the last reverted edit, returns false if there is nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let Some(edit) = self.redo.pop() else {
+            return false;
+        };
+        self.map
+            .replace_entry(&edit.qualifier, edit.stat, edit.after.clone());
+        self.undo.push(edit);
+        true
+    }
+}
@@ -1,12 +1,17 @@
-use crate::{attribute::Attribute, stat::StatValuePair, QualifierFlag, QualifierQuery, Querier};
+use crate::{
+    attribute::Attribute, stat::StatValuePair, QualifierFlag, QualifierQuery, Querier, StatError,
+    StatInst,
+};
 #[allow(unused)]
 use bevy_ecs::component::Component;
 use bevy_ecs::{
     entity::Entity,
-    query::{QueryData, WorldQuery},
+    query::{QueryData, QueryEntityError, WorldQuery},
     system::{Query, StaticSystemParam, SystemParam},
+    world::World,
 };
-use bevy_hierarchy::Children;
+use bevy_hierarchy::{Children, Parent};
+use std::{any::Any, marker::PhantomData};
 
 /// An isolated item that provides stat modifiers to a stat query.
 #[allow(unused_variables)]
@@ -22,6 +27,21 @@ pub trait StatStream {
     ) {
     }
 
+    /// Fallible version of [`StatStream::stream_stat`], queriers run this instead of
+    /// [`StatStream::stream_stat`] and report errors via `try_eval_stat`.
+    ///
+    /// By default calls [`StatStream::stream_stat`].
+    fn try_stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) -> Result<(), StatError> {
+        self.stream_stat(entity, qualifier, stat_value, querier);
+        Ok(())
+    }
+
     fn stream_relation(
         &self,
         other: &Self,
@@ -33,9 +53,47 @@ pub trait StatStream {
     ) {
     }
 
+    /// Fallible version of [`StatStream::stream_relation`], queriers run this instead of
+    /// [`StatStream::stream_relation`] and report errors via `try_eval_relation`.
+    ///
+    /// By default calls [`StatStream::stream_relation`].
+    fn try_stream_relation(
+        &self,
+        other: &Self,
+        entity: Entity,
+        target: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) -> Result<(), StatError> {
+        self.stream_relation(other, entity, target, qualifier, stat_value, querier);
+        Ok(())
+    }
+
+    /// Invoked once on the final value after all streams have run [`StatStream::stream_stat`]
+    /// or [`StatStream::stream_relation`], in the same order.
+    /// For relations `entity` is the source of the relation.
+    ///
+    /// Unlike bounds on a [`StatValue`](crate::StatValue), this can enforce constraints
+    /// depending on other stats, e.g. crit chance cannot exceed 100% no matter the sources.
+    fn finalize(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+    }
+
     fn has_attribute(&self, entity: Entity, attribute: Attribute) -> bool {
         false
     }
+
+    /// Returns `false` if this stream never modifies `stat`,
+    /// allowing queries to skip this stream entirely.
+    fn applies_to(&self, stat: StatInst) -> bool {
+        true
+    }
 }
 
 impl<T> StatStream for &T
@@ -54,6 +112,16 @@ where
         T::stream_stat(self, entity, qualifier, stat_value, querier);
     }
 
+    fn try_stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) -> Result<(), StatError> {
+        T::try_stream_stat(self, entity, qualifier, stat_value, querier)
+    }
+
     fn stream_relation(
         &self,
         other: &Self,
@@ -66,9 +134,35 @@ where
         T::stream_relation(self, other, entity, target, qualifier, stat_value, querier);
     }
 
+    fn try_stream_relation(
+        &self,
+        other: &Self,
+        entity: Entity,
+        target: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) -> Result<(), StatError> {
+        T::try_stream_relation(self, other, entity, target, qualifier, stat_value, querier)
+    }
+
+    fn finalize(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+        T::finalize(self, entity, qualifier, stat_value, querier);
+    }
+
     fn has_attribute(&self, entity: Entity, attribute: Attribute) -> bool {
         T::has_attribute(self, entity, attribute)
     }
+
+    fn applies_to(&self, stat: StatInst) -> bool {
+        T::applies_to(self, stat)
+    }
 }
 
 impl<A, B> StatStream for (A, B)
@@ -85,8 +179,35 @@ where
         stat_value: &mut StatValuePair,
         querier: Querier<Self::Qualifier>,
     ) {
-        self.0.stream_stat(entity, qualifier, stat_value, querier);
-        self.1.stream_stat(entity, qualifier, stat_value, querier);
+        if self.0.applies_to(stat_value.stat) {
+            self.0.stream_stat(entity, qualifier, stat_value, querier);
+        }
+        if self.1.applies_to(stat_value.stat) {
+            self.1.stream_stat(entity, qualifier, stat_value, querier);
+        }
+    }
+
+    /// Both streams are run, the first error is returned.
+    fn try_stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) -> Result<(), StatError> {
+        let mut result = Ok(());
+        if self.0.applies_to(stat_value.stat) {
+            result = self
+                .0
+                .try_stream_stat(entity, qualifier, stat_value, querier);
+        }
+        if self.1.applies_to(stat_value.stat) {
+            let second = self
+                .1
+                .try_stream_stat(entity, qualifier, stat_value, querier);
+            result = result.and(second);
+        }
+        result
     }
 
     fn stream_relation(
@@ -98,10 +219,54 @@ where
         stat_value: &mut StatValuePair,
         querier: Querier<Self::Qualifier>,
     ) {
-        self.0
-            .stream_relation(&other.0, entity, target, qualifier, stat_value, querier);
-        self.1
-            .stream_relation(&other.1, entity, target, qualifier, stat_value, querier);
+        if self.0.applies_to(stat_value.stat) {
+            self.0
+                .stream_relation(&other.0, entity, target, qualifier, stat_value, querier);
+        }
+        if self.1.applies_to(stat_value.stat) {
+            self.1
+                .stream_relation(&other.1, entity, target, qualifier, stat_value, querier);
+        }
+    }
+
+    /// Both streams are run, the first error is returned.
+    fn try_stream_relation(
+        &self,
+        other: &Self,
+        entity: Entity,
+        target: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) -> Result<(), StatError> {
+        let mut result = Ok(());
+        if self.0.applies_to(stat_value.stat) {
+            result = self
+                .0
+                .try_stream_relation(&other.0, entity, target, qualifier, stat_value, querier);
+        }
+        if self.1.applies_to(stat_value.stat) {
+            let second = self
+                .1
+                .try_stream_relation(&other.1, entity, target, qualifier, stat_value, querier);
+            result = result.and(second);
+        }
+        result
+    }
+
+    fn finalize(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+        if self.0.applies_to(stat_value.stat) {
+            self.0.finalize(entity, qualifier, stat_value, querier);
+        }
+        if self.1.applies_to(stat_value.stat) {
+            self.1.finalize(entity, qualifier, stat_value, querier);
+        }
     }
 
     fn has_attribute(&self, entity: Entity, attribute: Attribute) -> bool {
@@ -109,6 +274,297 @@ where
     }
 }
 
+/// An object safe version of [`StatStream`], implemented for all `'static` [`StatStream`]s.
+///
+/// `Box<dyn DynStatStream<Q>>` implements [`StatStream`],
+/// allowing heterogeneous streams to be stored in a [`Vec`].
+pub trait DynStatStream<Q: QualifierFlag>: Any {
+    fn dyn_stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Q>,
+    );
+
+    fn dyn_try_stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Q>,
+    ) -> Result<(), StatError>;
+
+    /// Does nothing if `other` is not of the same type as `self`.
+    fn dyn_stream_relation(
+        &self,
+        other: &dyn DynStatStream<Q>,
+        entity: Entity,
+        target: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Q>,
+    );
+
+    /// Does nothing if `other` is not of the same type as `self`.
+    fn dyn_try_stream_relation(
+        &self,
+        other: &dyn DynStatStream<Q>,
+        entity: Entity,
+        target: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Q>,
+    ) -> Result<(), StatError>;
+
+    fn dyn_finalize(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Q>,
+    );
+
+    fn dyn_has_attribute(&self, entity: Entity, attribute: Attribute) -> bool;
+
+    fn dyn_applies_to(&self, stat: StatInst) -> bool;
+
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: StatStream + 'static> DynStatStream<T::Qualifier> for T {
+    fn dyn_stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<T::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<T::Qualifier>,
+    ) {
+        self.stream_stat(entity, qualifier, stat_value, querier)
+    }
+
+    fn dyn_try_stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<T::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<T::Qualifier>,
+    ) -> Result<(), StatError> {
+        self.try_stream_stat(entity, qualifier, stat_value, querier)
+    }
+
+    fn dyn_stream_relation(
+        &self,
+        other: &dyn DynStatStream<T::Qualifier>,
+        entity: Entity,
+        target: Entity,
+        qualifier: &QualifierQuery<T::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<T::Qualifier>,
+    ) {
+        if let Some(other) = other.as_any().downcast_ref::<T>() {
+            self.stream_relation(other, entity, target, qualifier, stat_value, querier)
+        }
+    }
+
+    fn dyn_try_stream_relation(
+        &self,
+        other: &dyn DynStatStream<T::Qualifier>,
+        entity: Entity,
+        target: Entity,
+        qualifier: &QualifierQuery<T::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<T::Qualifier>,
+    ) -> Result<(), StatError> {
+        match other.as_any().downcast_ref::<T>() {
+            Some(other) => {
+                self.try_stream_relation(other, entity, target, qualifier, stat_value, querier)
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn dyn_finalize(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<T::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<T::Qualifier>,
+    ) {
+        self.finalize(entity, qualifier, stat_value, querier)
+    }
+
+    fn dyn_has_attribute(&self, entity: Entity, attribute: Attribute) -> bool {
+        self.has_attribute(entity, attribute)
+    }
+
+    fn dyn_applies_to(&self, stat: StatInst) -> bool {
+        self.applies_to(stat)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl<Q: QualifierFlag> StatStream for Box<dyn DynStatStream<Q>> {
+    type Qualifier = Q;
+
+    fn stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Q>,
+    ) {
+        (**self).dyn_stream_stat(entity, qualifier, stat_value, querier)
+    }
+
+    fn try_stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Q>,
+    ) -> Result<(), StatError> {
+        (**self).dyn_try_stream_stat(entity, qualifier, stat_value, querier)
+    }
+
+    fn stream_relation(
+        &self,
+        other: &Self,
+        entity: Entity,
+        target: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Q>,
+    ) {
+        (**self).dyn_stream_relation(&**other, entity, target, qualifier, stat_value, querier)
+    }
+
+    fn try_stream_relation(
+        &self,
+        other: &Self,
+        entity: Entity,
+        target: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Q>,
+    ) -> Result<(), StatError> {
+        (**self).dyn_try_stream_relation(&**other, entity, target, qualifier, stat_value, querier)
+    }
+
+    fn finalize(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Q>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Q>,
+    ) {
+        (**self).dyn_finalize(entity, qualifier, stat_value, querier)
+    }
+
+    fn has_attribute(&self, entity: Entity, attribute: Attribute) -> bool {
+        (**self).dyn_has_attribute(entity, attribute)
+    }
+
+    fn applies_to(&self, stat: StatInst) -> bool {
+        (**self).dyn_applies_to(stat)
+    }
+}
+
+/// Streams each item in order, relations are streamed pairwise by index.
+impl<T: StatStream> StatStream for Vec<T> {
+    type Qualifier = T::Qualifier;
+
+    fn stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+        for item in self {
+            if item.applies_to(stat_value.stat) {
+                item.stream_stat(entity, qualifier, stat_value, querier);
+            }
+        }
+    }
+
+    /// All items are run, the first error is returned.
+    fn try_stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) -> Result<(), StatError> {
+        let mut result = Ok(());
+        for item in self {
+            if item.applies_to(stat_value.stat) {
+                let next = item.try_stream_stat(entity, qualifier, stat_value, querier);
+                result = result.and(next);
+            }
+        }
+        result
+    }
+
+    fn stream_relation(
+        &self,
+        other: &Self,
+        entity: Entity,
+        target: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+        for (item, other) in self.iter().zip(other) {
+            if item.applies_to(stat_value.stat) {
+                item.stream_relation(other, entity, target, qualifier, stat_value, querier);
+            }
+        }
+    }
+
+    /// All items are run, the first error is returned.
+    fn try_stream_relation(
+        &self,
+        other: &Self,
+        entity: Entity,
+        target: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) -> Result<(), StatError> {
+        let mut result = Ok(());
+        for (item, other) in self.iter().zip(other) {
+            if item.applies_to(stat_value.stat) {
+                let next =
+                    item.try_stream_relation(other, entity, target, qualifier, stat_value, querier);
+                result = result.and(next);
+            }
+        }
+        result
+    }
+
+    fn finalize(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+        for item in self {
+            if item.applies_to(stat_value.stat) {
+                item.finalize(entity, qualifier, stat_value, querier);
+            }
+        }
+    }
+
+    fn has_attribute(&self, entity: Entity, attribute: Attribute) -> bool {
+        self.iter().any(|x| x.has_attribute(entity, attribute))
+    }
+}
+
 /// A set of [`Component`]s and external [`SystemParam`]s that provide
 /// stat modifiers for an [`Entity`].
 #[allow(unused_variables)]
@@ -117,17 +573,47 @@ pub trait QueryStream: 'static {
     type Query: QueryData + 'static;
     type Context: SystemParam + 'static;
 
-    fn stream_stat(
+    fn stream_stat(
+        query: <<Self::Query as QueryData>::ReadOnly as WorldQuery>::Item<'_>,
+        context: &<Self::Context as SystemParam>::Item<'_, '_>,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+    }
+
+    fn stream_relation(
+        this: <<Self::Query as QueryData>::ReadOnly as WorldQuery>::Item<'_>,
+        other: <<Self::Query as QueryData>::ReadOnly as WorldQuery>::Item<'_>,
+        context: &<Self::Context as SystemParam>::Item<'_, '_>,
+        entity: Entity,
+        target: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+    }
+
+    /// See [`StatStream::try_stream_stat`].
+    ///
+    /// By default calls [`QueryStream::stream_stat`].
+    fn try_stream_stat(
         query: <<Self::Query as QueryData>::ReadOnly as WorldQuery>::Item<'_>,
         context: &<Self::Context as SystemParam>::Item<'_, '_>,
         entity: Entity,
         qualifier: &QualifierQuery<Self::Qualifier>,
         stat_value: &mut StatValuePair,
         querier: Querier<Self::Qualifier>,
-    ) {
+    ) -> Result<(), StatError> {
+        Self::stream_stat(query, context, entity, qualifier, stat_value, querier);
+        Ok(())
     }
 
-    fn stream_relation(
+    /// See [`StatStream::try_stream_relation`].
+    ///
+    /// By default calls [`QueryStream::stream_relation`].
+    fn try_stream_relation(
         this: <<Self::Query as QueryData>::ReadOnly as WorldQuery>::Item<'_>,
         other: <<Self::Query as QueryData>::ReadOnly as WorldQuery>::Item<'_>,
         context: &<Self::Context as SystemParam>::Item<'_, '_>,
@@ -136,6 +622,21 @@ pub trait QueryStream: 'static {
         qualifier: &QualifierQuery<Self::Qualifier>,
         stat_value: &mut StatValuePair,
         querier: Querier<Self::Qualifier>,
+    ) -> Result<(), StatError> {
+        Self::stream_relation(
+            this, other, context, entity, target, qualifier, stat_value, querier,
+        );
+        Ok(())
+    }
+
+    /// See [`StatStream::finalize`].
+    fn finalize(
+        query: <<Self::Query as QueryData>::ReadOnly as WorldQuery>::Item<'_>,
+        context: &<Self::Context as SystemParam>::Item<'_, '_>,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
     ) {
     }
 
@@ -147,6 +648,13 @@ pub trait QueryStream: 'static {
     ) -> bool {
         false
     }
+
+    /// See [`StatStream::applies_to`].
+    ///
+    /// Checked by [`StatQuery`] and [`ChildQuery`] before any item is fetched.
+    fn applies_to(stat: StatInst) -> bool {
+        true
+    }
 }
 
 impl<T> QueryStream for T
@@ -165,7 +673,9 @@ where
         stat_value: &mut StatValuePair,
         querier: Querier<T::Qualifier>,
     ) {
-        query.stream_stat(entity, qualifier, stat_value, querier);
+        if query.applies_to(stat_value.stat) {
+            query.stream_stat(entity, qualifier, stat_value, querier);
+        }
     }
 
     fn stream_relation(
@@ -178,7 +688,52 @@ where
         stat_value: &mut StatValuePair,
         querier: Querier<T::Qualifier>,
     ) {
-        this.stream_relation(other, entity, target, qualifier, stat_value, querier);
+        if this.applies_to(stat_value.stat) {
+            this.stream_relation(other, entity, target, qualifier, stat_value, querier);
+        }
+    }
+
+    fn try_stream_stat(
+        query: &T,
+        _: &(),
+        entity: Entity,
+        qualifier: &QualifierQuery<T::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<T::Qualifier>,
+    ) -> Result<(), StatError> {
+        if !query.applies_to(stat_value.stat) {
+            return Ok(());
+        }
+        query.try_stream_stat(entity, qualifier, stat_value, querier)
+    }
+
+    fn try_stream_relation(
+        this: &T,
+        other: &T,
+        _: &(),
+        entity: Entity,
+        target: Entity,
+        qualifier: &QualifierQuery<T::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<T::Qualifier>,
+    ) -> Result<(), StatError> {
+        if !this.applies_to(stat_value.stat) {
+            return Ok(());
+        }
+        this.try_stream_relation(other, entity, target, qualifier, stat_value, querier)
+    }
+
+    fn finalize(
+        query: &T,
+        _: &(),
+        entity: Entity,
+        qualifier: &QualifierQuery<T::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<T::Qualifier>,
+    ) {
+        if query.applies_to(stat_value.stat) {
+            query.finalize(entity, qualifier, stat_value, querier);
+        }
     }
 
     fn has_attribute(query: &T, _: &(), entity: Entity, attribute: Attribute) -> bool {
@@ -187,6 +742,9 @@ where
 }
 
 /// [`SystemParam`] for querying a [`QueryStream`].
+///
+/// In `try_eval_stat` and `try_eval_relation`, a despawned entity or target is reported as
+/// [`StatError::QueryFailed`] or [`StatError::RelationFailed`], an entity not matching the query is skipped.
 #[derive(SystemParam)]
 pub struct StatQuery<'w, 's, T: QueryStream> {
     pub query: Query<'w, 's, <<T as QueryStream>::Query as QueryData>::ReadOnly>,
@@ -241,6 +799,66 @@ impl<T: QueryStream> StatStream for StatQuery<'_, '_, T> {
         }
     }
 
+    fn try_stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) -> Result<(), StatError> {
+        match self.query.get(entity) {
+            Ok(item) => {
+                T::try_stream_stat(item, &self.context, entity, qualifier, stat_value, querier)
+            }
+            Err(QueryEntityError::NoSuchEntity(_)) => Err(StatError::QueryFailed {
+                entity,
+                stat: stat_value.stat.name(),
+            }),
+            Err(_) => Ok(()),
+        }
+    }
+
+    fn try_stream_relation(
+        &self,
+        _: &Self,
+        entity: Entity,
+        target: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) -> Result<(), StatError> {
+        match self.query.get_many([entity, target]) {
+            Ok([this, other]) => T::try_stream_relation(
+                this,
+                other,
+                &self.context,
+                entity,
+                target,
+                qualifier,
+                stat_value,
+                querier,
+            ),
+            Err(QueryEntityError::NoSuchEntity(_)) => Err(StatError::RelationFailed {
+                from: entity,
+                to: target,
+                stat: stat_value.stat.name(),
+            }),
+            Err(_) => Ok(()),
+        }
+    }
+
+    fn finalize(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+        if let Ok(item) = self.query.get(entity) {
+            T::finalize(item, &self.context, entity, qualifier, stat_value, querier);
+        }
+    }
+
     fn has_attribute(&self, entity: Entity, attribute: Attribute) -> bool {
         if let Ok(item) = self.query.get(entity) {
             T::has_attribute(item, &self.context, entity, attribute)
@@ -248,6 +866,10 @@ impl<T: QueryStream> StatStream for StatQuery<'_, '_, T> {
             false
         }
     }
+
+    fn applies_to(&self, stat: StatInst) -> bool {
+        T::applies_to(stat)
+    }
 }
 
 impl<T: QueryStream> StatStream for StatQueryMut<'_, '_, T> {
@@ -288,6 +910,66 @@ impl<T: QueryStream> StatStream for StatQueryMut<'_, '_, T> {
         }
     }
 
+    fn try_stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) -> Result<(), StatError> {
+        match self.query.get(entity) {
+            Ok(item) => {
+                T::try_stream_stat(item, &self.context, entity, qualifier, stat_value, querier)
+            }
+            Err(QueryEntityError::NoSuchEntity(_)) => Err(StatError::QueryFailed {
+                entity,
+                stat: stat_value.stat.name(),
+            }),
+            Err(_) => Ok(()),
+        }
+    }
+
+    fn try_stream_relation(
+        &self,
+        _: &Self,
+        entity: Entity,
+        target: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) -> Result<(), StatError> {
+        match self.query.get_many([entity, target]) {
+            Ok([this, other]) => T::try_stream_relation(
+                this,
+                other,
+                &self.context,
+                entity,
+                target,
+                qualifier,
+                stat_value,
+                querier,
+            ),
+            Err(QueryEntityError::NoSuchEntity(_)) => Err(StatError::RelationFailed {
+                from: entity,
+                to: target,
+                stat: stat_value.stat.name(),
+            }),
+            Err(_) => Ok(()),
+        }
+    }
+
+    fn finalize(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+        if let Ok(item) = self.query.get(entity) {
+            T::finalize(item, &self.context, entity, qualifier, stat_value, querier);
+        }
+    }
+
     fn has_attribute(&self, entity: Entity, attribute: Attribute) -> bool {
         if let Ok(item) = self.query.get(entity) {
             T::has_attribute(item, &self.context, entity, attribute)
@@ -295,6 +977,142 @@ impl<T: QueryStream> StatStream for StatQueryMut<'_, '_, T> {
             false
         }
     }
+
+    fn applies_to(&self, stat: StatInst) -> bool {
+        T::applies_to(stat)
+    }
+}
+
+/// A [`StatStream`] reading a [`Component`] [`StatStream`] from a [`World`] directly.
+///
+/// This is the [`World`] based counterpart of [`StatQuery`],
+/// useful for one-off queries in tools, exclusive systems and tests.
+pub struct WorldStream<'w, T: Component + StatStream> {
+    world: &'w World,
+    p: PhantomData<T>,
+}
+
+impl<'w, T: Component + StatStream> WorldStream<'w, T> {
+    pub fn new(world: &'w World) -> Self {
+        WorldStream {
+            world,
+            p: PhantomData,
+        }
+    }
+}
+
+impl<T: Component + StatStream> Clone for WorldStream<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Component + StatStream> Copy for WorldStream<'_, T> {}
+
+impl<T: Component + StatStream> StatStream for WorldStream<'_, T> {
+    type Qualifier = T::Qualifier;
+
+    fn stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+        if let Some(item) = self.world.get::<T>(entity) {
+            if item.applies_to(stat_value.stat) {
+                item.stream_stat(entity, qualifier, stat_value, querier);
+            }
+        }
+    }
+
+    fn try_stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) -> Result<(), StatError> {
+        let Ok(entity_ref) = self.world.get_entity(entity) else {
+            return Err(StatError::QueryFailed {
+                entity,
+                stat: stat_value.stat.name(),
+            });
+        };
+        match entity_ref.get::<T>() {
+            Some(item) if item.applies_to(stat_value.stat) => {
+                item.try_stream_stat(entity, qualifier, stat_value, querier)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn stream_relation(
+        &self,
+        _: &Self,
+        entity: Entity,
+        target: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+        if let (Some(this), Some(other)) =
+            (self.world.get::<T>(entity), self.world.get::<T>(target))
+        {
+            if this.applies_to(stat_value.stat) {
+                this.stream_relation(other, entity, target, qualifier, stat_value, querier);
+            }
+        }
+    }
+
+    fn try_stream_relation(
+        &self,
+        _: &Self,
+        entity: Entity,
+        target: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) -> Result<(), StatError> {
+        let (Ok(this), Ok(other)) = (self.world.get_entity(entity), self.world.get_entity(target))
+        else {
+            return Err(StatError::RelationFailed {
+                from: entity,
+                to: target,
+                stat: stat_value.stat.name(),
+            });
+        };
+        match (this.get::<T>(), other.get::<T>()) {
+            (Some(this), Some(other)) if this.applies_to(stat_value.stat) => {
+                this.try_stream_relation(other, entity, target, qualifier, stat_value, querier)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn finalize(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+        if let Some(item) = self.world.get::<T>(entity) {
+            if item.applies_to(stat_value.stat) {
+                item.finalize(entity, qualifier, stat_value, querier);
+            }
+        }
+    }
+
+    fn has_attribute(&self, entity: Entity, attribute: Attribute) -> bool {
+        self.world
+            .get::<T>(entity)
+            .is_some_and(|item| item.has_attribute(entity, attribute))
+    }
+
+    fn applies_to(&self, stat: StatInst) -> bool {
+        <T as QueryStream>::applies_to(stat)
+    }
 }
 
 /// A component that references other entities, like [`Children`].
@@ -308,6 +1126,12 @@ impl EntityReference for Children {
     }
 }
 
+impl EntityReference for Parent {
+    fn iter_entities(&self) -> impl Iterator<Item = Entity> {
+        std::iter::once(self.get())
+    }
+}
+
 /// [`SystemParam`] for querying [`QueryStream`]s on entities referenced by a component like [`Children`].
 ///
 /// `query_relation` implementation is disabled since the behavior is undefined.
@@ -328,6 +1152,14 @@ pub struct ChildQueryMut<'w, 's, T: QueryStream, C: EntityReference = Children>
     pub children: Query<'w, 's, &'static C>,
 }
 
+/// [`SystemParam`] for querying [`QueryStream`]s on the [`Parent`] of an entity,
+/// e.g. a parent's aura modifying stats of its children.
+pub type ParentQuery<'w, 's, T> = ChildQuery<'w, 's, T, Parent>;
+
+/// [`SystemParam`] for querying [`QueryStream`]s on the [`Parent`] of an entity,
+/// e.g. a parent's aura modifying stats of its children.
+pub type ParentQueryMut<'w, 's, T> = ChildQueryMut<'w, 's, T, Parent>;
+
 impl<T: QueryStream, C: EntityReference> StatStream for ChildQuery<'_, '_, T, C> {
     type Qualifier = T::Qualifier;
 
@@ -345,6 +1177,39 @@ impl<T: QueryStream, C: EntityReference> StatStream for ChildQuery<'_, '_, T, C>
         }
     }
 
+    /// All children are run, the first error is returned.
+    fn try_stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) -> Result<(), StatError> {
+        let mut result = Ok(());
+        if let Ok(children) = self.children.get(entity) {
+            for item in self.query.iter_many(children.iter_entities()) {
+                let next =
+                    T::try_stream_stat(item, &self.context, entity, qualifier, stat_value, querier);
+                result = result.and(next);
+            }
+        }
+        result
+    }
+
+    fn finalize(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+        if let Ok(children) = self.children.get(entity) {
+            for item in self.query.iter_many(children.iter_entities()) {
+                T::finalize(item, &self.context, entity, qualifier, stat_value, querier);
+            }
+        }
+    }
+
     fn has_attribute(&self, entity: Entity, attribute: Attribute) -> bool {
         if let Ok(children) = self.children.get(entity) {
             for item in self.query.iter_many(children.iter_entities()) {
@@ -355,6 +1220,10 @@ impl<T: QueryStream, C: EntityReference> StatStream for ChildQuery<'_, '_, T, C>
         }
         false
     }
+
+    fn applies_to(&self, stat: StatInst) -> bool {
+        T::applies_to(stat)
+    }
 }
 
 impl<T: QueryStream, C: EntityReference> StatStream for ChildQueryMut<'_, '_, T, C> {
@@ -374,6 +1243,39 @@ impl<T: QueryStream, C: EntityReference> StatStream for ChildQueryMut<'_, '_, T,
         }
     }
 
+    /// All children are run, the first error is returned.
+    fn try_stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) -> Result<(), StatError> {
+        let mut result = Ok(());
+        if let Ok(children) = self.children.get(entity) {
+            for item in self.query.iter_many(children.iter_entities()) {
+                let next =
+                    T::try_stream_stat(item, &self.context, entity, qualifier, stat_value, querier);
+                result = result.and(next);
+            }
+        }
+        result
+    }
+
+    fn finalize(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+        if let Ok(children) = self.children.get(entity) {
+            for item in self.query.iter_many(children.iter_entities()) {
+                T::finalize(item, &self.context, entity, qualifier, stat_value, querier);
+            }
+        }
+    }
+
     fn has_attribute(&self, entity: Entity, attribute: Attribute) -> bool {
         if let Ok(children) = self.children.get(entity) {
             for item in self.query.iter_many(children.iter_entities()) {
@@ -384,4 +1286,8 @@ impl<T: QueryStream, C: EntityReference> StatStream for ChildQueryMut<'_, '_, T,
         }
         false
     }
+
+    fn applies_to(&self, stat: StatInst) -> bool {
+        T::applies_to(stat)
+    }
 }
@@ -0,0 +1,409 @@
+use crate::{
+    Attribute, Qualifier, QualifierQuery, Querier, Stat, StatError, StatInst, StatStream,
+    StatValue, StatValuePair,
+};
+use bevy_ecs::entity::Entity;
+
+/// Combinator methods on [`StatStream`].
+///
+/// ```
+/// # use bevy_stat_query::{Qualifier, StatMap, StatStreamExt};
+/// let fire = 1u32;
+/// let map = StatMap::<u32>::new();
+/// let other_map = StatMap::<u32>::new();
+/// let stream = (&map)
+///     .qualified(Qualifier::all_of(fire))
+///     .when_attr("burning")
+///     .and(&other_map);
+/// ```
+pub trait StatStreamExt: StatStream + Sized {
+    /// Stream `self`, then `other`, same as `(self, other)`.
+    fn and<T: StatStream<Qualifier = Self::Qualifier>>(self, other: T) -> (Self, T) {
+        (self, other)
+    }
+
+    /// Only stream if the queried entity has an [`Attribute`].
+    fn when_attr(self, attribute: impl Into<Attribute<'static>>) -> WhenAttr<Self> {
+        WhenAttr {
+            stream: self,
+            attribute: attribute.into(),
+        }
+    }
+
+    /// Only stream if the [`Qualifier`] qualifies as the queried [`QualifierQuery`].
+    fn qualified(self, qualifier: Qualifier<Self::Qualifier>) -> Qualified<Self> {
+        Qualified {
+            stream: self,
+            qualifier,
+        }
+    }
+
+    /// Scale contributions to `stat` via [`StatValue::scale`].
+    ///
+    /// Contributions are collected on a default value before being scaled and joined,
+    /// contributions equal to the default value are ignored.
+    fn scaled<S: Stat<Value: PartialEq>>(
+        self,
+        stat: S,
+        factor: <S::Value as StatValue>::Mul,
+    ) -> Scaled<Self, S> {
+        Scaled {
+            stream: self,
+            stat,
+            factor,
+        }
+    }
+}
+
+impl<T: StatStream> StatStreamExt for T {}
+
+/// A [`StatStream`] that only streams if the queried entity has an [`Attribute`].
+#[derive(Debug, Clone)]
+pub struct WhenAttr<S> {
+    pub stream: S,
+    pub attribute: Attribute<'static>,
+}
+
+impl<S: StatStream> StatStream for WhenAttr<S> {
+    type Qualifier = S::Qualifier;
+
+    fn stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+        if querier.has_attribute(entity, self.attribute) {
+            self.stream
+                .stream_stat(entity, qualifier, stat_value, querier);
+        }
+    }
+
+    fn try_stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) -> Result<(), StatError> {
+        if querier.has_attribute(entity, self.attribute) {
+            self.stream
+                .try_stream_stat(entity, qualifier, stat_value, querier)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn stream_relation(
+        &self,
+        other: &Self,
+        entity: Entity,
+        target: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+        if querier.has_attribute(entity, self.attribute) {
+            self.stream.stream_relation(
+                &other.stream,
+                entity,
+                target,
+                qualifier,
+                stat_value,
+                querier,
+            );
+        }
+    }
+
+    fn try_stream_relation(
+        &self,
+        other: &Self,
+        entity: Entity,
+        target: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) -> Result<(), StatError> {
+        if querier.has_attribute(entity, self.attribute) {
+            self.stream.try_stream_relation(
+                &other.stream,
+                entity,
+                target,
+                qualifier,
+                stat_value,
+                querier,
+            )
+        } else {
+            Ok(())
+        }
+    }
+
+    fn finalize(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+        if querier.has_attribute(entity, self.attribute) {
+            self.stream.finalize(entity, qualifier, stat_value, querier);
+        }
+    }
+
+    fn has_attribute(&self, entity: Entity, attribute: Attribute) -> bool {
+        self.stream.has_attribute(entity, attribute)
+    }
+
+    fn applies_to(&self, stat: StatInst) -> bool {
+        self.stream.applies_to(stat)
+    }
+}
+
+/// A [`StatStream`] that only streams if its [`Qualifier`] qualifies as the queried [`QualifierQuery`].
+#[derive(Debug, Clone)]
+pub struct Qualified<S: StatStream> {
+    pub stream: S,
+    pub qualifier: Qualifier<S::Qualifier>,
+}
+
+impl<S: StatStream> StatStream for Qualified<S> {
+    type Qualifier = S::Qualifier;
+
+    fn stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+        if self.qualifier.qualifies_as(qualifier) {
+            self.stream
+                .stream_stat(entity, qualifier, stat_value, querier);
+        }
+    }
+
+    fn try_stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) -> Result<(), StatError> {
+        if self.qualifier.qualifies_as(qualifier) {
+            self.stream
+                .try_stream_stat(entity, qualifier, stat_value, querier)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn stream_relation(
+        &self,
+        other: &Self,
+        entity: Entity,
+        target: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+        if self.qualifier.qualifies_as(qualifier) {
+            self.stream.stream_relation(
+                &other.stream,
+                entity,
+                target,
+                qualifier,
+                stat_value,
+                querier,
+            );
+        }
+    }
+
+    fn try_stream_relation(
+        &self,
+        other: &Self,
+        entity: Entity,
+        target: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) -> Result<(), StatError> {
+        if self.qualifier.qualifies_as(qualifier) {
+            self.stream.try_stream_relation(
+                &other.stream,
+                entity,
+                target,
+                qualifier,
+                stat_value,
+                querier,
+            )
+        } else {
+            Ok(())
+        }
+    }
+
+    fn finalize(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+        if self.qualifier.qualifies_as(qualifier) {
+            self.stream.finalize(entity, qualifier, stat_value, querier);
+        }
+    }
+
+    fn has_attribute(&self, entity: Entity, attribute: Attribute) -> bool {
+        self.stream.has_attribute(entity, attribute)
+    }
+
+    fn applies_to(&self, stat: StatInst) -> bool {
+        self.stream.applies_to(stat)
+    }
+}
+
+/// A [`StatStream`] that scales its contributions to a stat via [`StatValue::scale`].
+///
+/// Other stats are streamed unchanged.
+#[derive(Debug, Clone)]
+pub struct Scaled<S, T: Stat> {
+    pub stream: S,
+    pub stat: T,
+    pub factor: <T::Value as StatValue>::Mul,
+}
+
+impl<S: StatStream, T: Stat<Value: PartialEq>> Scaled<S, T> {
+    fn scale_into(&self, stat_value: &mut StatValuePair, f: impl FnOnce(&mut StatValuePair)) {
+        let mut contribution = StatValuePair::new_default(&self.stat);
+        f(&mut contribution);
+        let Some(mut contribution) = contribution.into_result::<T>() else {
+            return;
+        };
+        if contribution == T::Value::default() {
+            return;
+        }
+        contribution.scale(self.factor.clone());
+        if let Some(value) = stat_value.is_then_cast(&self.stat) {
+            value.join(contribution);
+        }
+    }
+}
+
+impl<S: StatStream, T: Stat<Value: PartialEq>> StatStream for Scaled<S, T> {
+    type Qualifier = S::Qualifier;
+
+    fn stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+        if !stat_value.is(&self.stat) {
+            return self
+                .stream
+                .stream_stat(entity, qualifier, stat_value, querier);
+        }
+        self.scale_into(stat_value, |pair| {
+            self.stream.stream_stat(entity, qualifier, pair, querier)
+        });
+    }
+
+    fn try_stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) -> Result<(), StatError> {
+        if !stat_value.is(&self.stat) {
+            return self
+                .stream
+                .try_stream_stat(entity, qualifier, stat_value, querier);
+        }
+        let mut result = Ok(());
+        self.scale_into(stat_value, |pair| {
+            result = self
+                .stream
+                .try_stream_stat(entity, qualifier, pair, querier)
+        });
+        result
+    }
+
+    fn stream_relation(
+        &self,
+        other: &Self,
+        entity: Entity,
+        target: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+        if !stat_value.is(&self.stat) {
+            return self.stream.stream_relation(
+                &other.stream,
+                entity,
+                target,
+                qualifier,
+                stat_value,
+                querier,
+            );
+        }
+        self.scale_into(stat_value, |pair| {
+            self.stream
+                .stream_relation(&other.stream, entity, target, qualifier, pair, querier)
+        });
+    }
+
+    fn try_stream_relation(
+        &self,
+        other: &Self,
+        entity: Entity,
+        target: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) -> Result<(), StatError> {
+        if !stat_value.is(&self.stat) {
+            return self.stream.try_stream_relation(
+                &other.stream,
+                entity,
+                target,
+                qualifier,
+                stat_value,
+                querier,
+            );
+        }
+        let mut result = Ok(());
+        self.scale_into(stat_value, |pair| {
+            result = self.stream.try_stream_relation(
+                &other.stream,
+                entity,
+                target,
+                qualifier,
+                pair,
+                querier,
+            )
+        });
+        result
+    }
+
+    fn finalize(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<Self::Qualifier>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<Self::Qualifier>,
+    ) {
+        self.stream.finalize(entity, qualifier, stat_value, querier)
+    }
+
+    fn has_attribute(&self, entity: Entity, attribute: Attribute) -> bool {
+        self.stream.has_attribute(entity, attribute)
+    }
+
+    fn applies_to(&self, stat: StatInst) -> bool {
+        self.stream.applies_to(stat)
+    }
+}
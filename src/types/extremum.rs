@@ -0,0 +1,105 @@
+use super::is_clamped;
+use crate::{operations::Unsupported, Number, StatValue};
+use bevy_reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+/// A stat that keeps the highest contribution instead of summing, see [`StatExtremum`].
+pub type StatMaxAgg<T> = StatExtremum<T, true>;
+
+/// A stat that keeps the lowest contribution instead of summing, see [`StatExtremum`].
+pub type StatMinAgg<T> = StatExtremum<T, false>;
+
+/// A stat that keeps the most extreme contribution, the maximum if `MAX`, otherwise the minimum,
+/// e.g. only the strongest movement speed aura applies.
+///
+/// [`StatValue::add`] and [`StatValue::join`] both take the extreme value.
+/// If nothing contributed, evaluates to [`Number::LOWEST`] if `MAX`
+/// or [`Number::HIGHEST`] otherwise, clamped by bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Reflect)]
+#[repr(C, align(8))]
+pub struct StatExtremum<T: Number, const MAX: bool> {
+    value: T,
+    min: T,
+    max: T,
+}
+
+impl<T: Number, const MAX: bool> Default for StatExtremum<T, MAX> {
+    fn default() -> Self {
+        Self {
+            value: if MAX { T::LOWEST } else { T::HIGHEST },
+            min: T::LOWEST,
+            max: T::HIGHEST,
+        }
+    }
+}
+
+impl<T: Number, const MAX: bool> StatExtremum<T, MAX> {
+    fn pick(&mut self, other: T) {
+        if (MAX && other > self.value) || (!MAX && other < self.value) {
+            self.value = other
+        }
+    }
+}
+
+impl<T: Number, const MAX: bool> StatValue for StatExtremum<T, MAX> {
+    type Out = T;
+    type Base = T;
+
+    fn join(&mut self, other: Self) {
+        self.pick(other.value);
+        self.min(other.min);
+        self.max(other.max);
+    }
+
+    fn eval(&self) -> Self::Out {
+        if self.value > self.max {
+            self.max
+        } else if self.value < self.min {
+            self.min
+        } else {
+            self.value
+        }
+    }
+
+    type Add = T;
+    type Mul = Unsupported;
+    type Bounds = T;
+
+    type Bit = Unsupported;
+
+    fn add(&mut self, other: Self::Add) {
+        self.pick(other)
+    }
+
+    fn min(&mut self, other: Self::Bounds) {
+        if other > self.min {
+            self.min = other
+        }
+    }
+
+    fn max(&mut self, other: Self::Bounds) {
+        if other < self.max {
+            self.max = other
+        }
+    }
+
+    fn upper_bound(&self) -> Option<Self::Bounds> {
+        (self.max != T::HIGHEST).then_some(self.max)
+    }
+
+    fn is_clamped(&self) -> bool {
+        // The value is at a default bound if nothing contributed.
+        self.value != Self::default().value && is_clamped(self.value, self.min, self.max)
+    }
+
+    fn from_base(base: Self::Base) -> Self {
+        Self {
+            value: base,
+            ..Default::default()
+        }
+    }
+
+    fn into_base(self) -> Option<Self::Base> {
+        Some(self.value)
+    }
+}
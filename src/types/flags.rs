@@ -1,13 +1,16 @@
 use crate::num_traits::Flags;
-use bevy_reflect::TypePath;
-use serde::{Deserialize, Serialize};
+use bevy_reflect::Reflect;
+use serde::{Deserialize, Deserializer, Serialize};
 use std::fmt::Debug;
 
-use crate::{operations::Unsupported, StatValue};
+use crate::{
+    operations::{OpMerger, StatOperation, Unsupported},
+    EvalValue, Shareable, StatValue,
+};
 
 /// A flags based on a type that supports bitwise operations,
 /// like integer, `bitflgs` or `enumset`.
-#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, TypePath)]
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, Reflect)]
 #[repr(transparent)]
 pub struct StatFlags<T: Flags>(T);
 
@@ -36,4 +39,228 @@ impl<T: Flags> StatValue for StatFlags<T> {
     fn from_base(base: Self::Base) -> Self {
         Self(base)
     }
+
+    fn into_base(self) -> Option<Self::Base> {
+        Some(self.0)
+    }
+
+    fn simplify_ops(ops: Vec<StatOperation<Self>>) -> Vec<StatOperation<Self>> {
+        OpMerger {
+            or: Some(|a, b| a | b),
+            ..OpMerger::NONE
+        }
+        .simplify(ops)
+    }
+}
+
+/// A flags stat that supports both setting and clearing flags.
+///
+/// Unlike [`StatFlags`], flags cleared via [`StatFlagsOverride::exclude`] are removed
+/// from the result regardless of join order, clearing always wins over setting.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, Reflect)]
+pub struct StatFlagsOverride<T: Flags> {
+    set: T,
+    clear: T,
+}
+
+impl<T: Flags> StatFlagsOverride<T> {
+    /// Clear flags from the result, even if set by other sources.
+    pub fn exclude(&mut self, other: T) {
+        self.clear |= other
+    }
+
+    pub fn with_exclude(mut self, other: T) -> Self {
+        self.exclude(other);
+        self
+    }
+}
+
+impl<T: Flags> StatValue for StatFlagsOverride<T> {
+    type Out = T;
+    type Base = T;
+
+    fn join(&mut self, other: Self) {
+        self.set |= other.set;
+        self.clear |= other.clear;
+    }
+
+    fn eval(&self) -> Self::Out {
+        self.set.clone().exclude(self.clear.clone())
+    }
+
+    type Add = Unsupported;
+    type Mul = Unsupported;
+    type Bounds = Unsupported;
+
+    type Bit = T;
+
+    fn or(&mut self, other: Self::Bit) {
+        self.set |= other
+    }
+
+    fn from_base(base: Self::Base) -> Self {
+        Self {
+            set: base,
+            clear: T::default(),
+        }
+    }
+
+    fn into_base(self) -> Option<Self::Base> {
+        Some(self.set)
+    }
+
+    fn simplify_ops(ops: Vec<StatOperation<Self>>) -> Vec<StatOperation<Self>> {
+        OpMerger {
+            or: Some(|a, b| a | b),
+            ..OpMerger::NONE
+        }
+        .simplify(ops)
+    }
+}
+
+/// A boolean stat that is true only if every contributor is true, e.g. "can cast".
+///
+/// Unlike `bool`, which joins by `or`, this joins by `and`,
+/// so the [`Default`] is `true` as the identity of `and`.
+/// [`StatValue::or`] also performs `and` since it is the only bitwise operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[repr(C, align(8))]
+pub struct StatAll(pub bool);
+
+impl Default for StatAll {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+impl StatAll {
+    pub fn and(&mut self, other: bool) {
+        self.0 &= other
+    }
+
+    pub fn with_and(mut self, other: bool) -> Self {
+        self.and(other);
+        self
+    }
+}
+
+impl StatValue for StatAll {
+    type Out = bool;
+    type Base = bool;
+
+    fn join(&mut self, other: Self) {
+        self.0 &= other.0
+    }
+
+    fn eval(&self) -> Self::Out {
+        self.0
+    }
+
+    type Add = Unsupported;
+    type Mul = Unsupported;
+    type Bounds = Unsupported;
+
+    type Bit = bool;
+
+    fn or(&mut self, other: Self::Bit) {
+        self.and(other)
+    }
+
+    fn visit_eval(&self, f: &mut dyn FnMut(EvalValue)) {
+        f(EvalValue::Bool(self.0))
+    }
+
+    fn from_base(base: Self::Base) -> Self {
+        Self(base)
+    }
+
+    fn into_base(self) -> Option<Self::Base> {
+        Some(self.0)
+    }
+
+    fn simplify_ops(ops: Vec<StatOperation<Self>>) -> Vec<StatOperation<Self>> {
+        OpMerger {
+            or: Some(|a, b| a & b),
+            ..OpMerger::NONE
+        }
+        .simplify(ops)
+    }
+}
+
+/// A set collecting every contributed value, e.g. all damage types applied by a hit.
+///
+/// Backed by a sorted [`Vec`], [`StatValue::or`] inserts a value and joining takes the union.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+pub struct StatSet<T: Ord + Shareable>(
+    #[serde(deserialize_with = "deserialize_sorted")]
+    #[serde(bound(deserialize = "T: Deserialize<'de>"))]
+    Vec<T>,
+);
+
+fn deserialize_sorted<'de, T: Ord + Deserialize<'de>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<T>, D::Error> {
+    let mut vec = Vec::<T>::deserialize(deserializer)?;
+    vec.sort();
+    vec.dedup();
+    Ok(vec)
+}
+
+impl<T: Ord + Shareable> Default for StatSet<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T: Ord + Shareable> StatSet<T> {
+    /// Returns true if the value was contributed.
+    pub fn contains(&self, value: &T) -> bool {
+        self.0.binary_search(value).is_ok()
+    }
+
+    /// Returns all contributed values in order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T: Ord + Shareable> StatValue for StatSet<T> {
+    type Out = Vec<T>;
+    type Base = Vec<T>;
+
+    fn join(&mut self, other: Self) {
+        self.0.extend(other.0);
+        self.0.sort();
+        self.0.dedup();
+    }
+
+    fn eval(&self) -> Self::Out {
+        self.0.clone()
+    }
+
+    fn finish(self) -> Self::Out {
+        self.0
+    }
+
+    type Add = Unsupported;
+    type Mul = Unsupported;
+    type Bounds = Unsupported;
+
+    type Bit = T;
+
+    fn or(&mut self, other: Self::Bit) {
+        if let Err(at) = self.0.binary_search(&other) {
+            self.0.insert(at, other)
+        }
+    }
+
+    fn from_base(mut base: Self::Base) -> Self {
+        base.sort();
+        base.dedup();
+        Self(base)
+    }
+
+    fn into_base(self) -> Option<Self::Base> {
+        Some(self.0)
+    }
 }
@@ -1,10 +1,16 @@
-use crate::Float;
-use crate::{operations::Unsupported, StatValue};
-use bevy_reflect::TypePath;
+use super::{canonical_bounds, is_clamped, rebase_addend};
+use crate::num_traits::lerp_float;
+use crate::rounding::{Round, Rounding};
+use crate::{
+    operations::{OpMerger, StatOperation, Unsupported},
+    EvalValue, StatValue,
+};
+use crate::{Float, Fraction};
+use bevy_reflect::Reflect;
 use serde::{Deserialize, Serialize};
 
 /// A stat represented by a floating point number or a fraction.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TypePath)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Reflect)]
 #[repr(C, align(8))]
 pub struct StatFloat<T: Float> {
     addend: T,
@@ -24,6 +30,13 @@ impl<T: Float> Default for StatFloat<T> {
     }
 }
 
+impl<T: Float> StatFloat<T> {
+    /// Evaluate without clamping.
+    fn unclamped(&self) -> T {
+        self.addend * self.mult
+    }
+}
+
 impl<T: Float> StatValue for StatFloat<T> {
     type Out = T;
     type Base = T;
@@ -36,7 +49,7 @@ impl<T: Float> StatValue for StatFloat<T> {
     }
 
     fn eval(&self) -> Self::Out {
-        (self.addend * self.mult).min(self.max).max(self.min)
+        self.unclamped().min(self.max).max(self.min)
     }
 
     type Add = T;
@@ -53,6 +66,10 @@ impl<T: Float> StatValue for StatFloat<T> {
         self.mult *= other;
     }
 
+    fn scale(&mut self, factor: Self::Mul) {
+        self.addend *= factor;
+    }
+
     fn min(&mut self, other: Self::Bounds) {
         self.min = self.min.max(other)
     }
@@ -61,6 +78,36 @@ impl<T: Float> StatValue for StatFloat<T> {
         self.max = self.max.min(other)
     }
 
+    fn upper_bound(&self) -> Option<Self::Bounds> {
+        (self.max != Float::MAX_VALUE).then_some(self.max)
+    }
+
+    fn is_clamped(&self) -> bool {
+        is_clamped(self.unclamped(), self.min, self.max)
+    }
+
+    fn rebase(&mut self, old: &Self, new: &Self) {
+        rebase_addend(&mut self.addend, old.addend, new.addend)
+    }
+
+    fn weighted_join(&mut self, other: Self, weight: Fraction<i32>) {
+        let addend = lerp_float(self.addend, other.addend, weight);
+        self.join(other);
+        self.addend = addend;
+    }
+
+    fn canonicalize(&mut self) {
+        canonical_bounds(self.min, &mut self.max)
+    }
+
+    fn is_canonical(&self) -> bool {
+        self.min <= self.max
+    }
+
+    fn visit_eval(&self, f: &mut dyn FnMut(EvalValue)) {
+        f(EvalValue::Float(self.eval().to_f64()))
+    }
+
     fn from_base(base: Self::Base) -> Self {
         Self {
             addend: base,
@@ -69,10 +116,274 @@ impl<T: Float> StatValue for StatFloat<T> {
             mult: T::ONE,
         }
     }
+
+    fn into_base(self) -> Option<Self::Base> {
+        Some(self.addend)
+    }
+
+    fn simplify_ops(ops: Vec<StatOperation<Self>>) -> Vec<StatOperation<Self>> {
+        OpMerger {
+            add: Some(|a, b| a + b),
+            mul: Some(|a, b| a * b),
+            min: Some(Float::max),
+            max: Some(Float::min),
+            ..OpMerger::NONE
+        }
+        .simplify(ops)
+    }
+}
+
+/// A [`StatFloat`] with its evaluated value snapped to an integer by a [`Rounding`] strategy,
+/// e.g. for currencies stored as floats.
+///
+/// Rounding is applied after clamping by `min` and `max`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Reflect)]
+#[repr(C, align(8))]
+pub struct StatFloatRounded<T: Float, R: Rounding = Round> {
+    value: StatFloat<T>,
+    #[reflect(ignore)]
+    rounding: R,
+}
+
+impl<T: Float, R: Rounding> StatFloatRounded<T, R> {
+    /// Set the [`Rounding`] method, e.g. a [`DynRounding`](crate::rounding::DynRounding) chosen at runtime.
+    pub fn with_rounding(mut self, rounding: R) -> Self {
+        self.rounding = rounding;
+        self
+    }
+}
+
+impl<T: Float, R: Rounding> StatValue for StatFloatRounded<T, R> {
+    type Out = T;
+    type Base = T;
+
+    fn join(&mut self, other: Self) {
+        self.value.join(other.value)
+    }
+
+    fn eval(&self) -> Self::Out {
+        self.rounding.round(self.value.eval())
+    }
+
+    type Add = T;
+    type Mul = T;
+    type Bounds = T;
+
+    type Bit = Unsupported;
+
+    fn add(&mut self, other: Self::Add) {
+        self.value.add(other)
+    }
+
+    fn mul(&mut self, other: Self::Mul) {
+        self.value.mul(other)
+    }
+
+    fn scale(&mut self, factor: Self::Mul) {
+        self.value.scale(factor)
+    }
+
+    fn min(&mut self, other: Self::Bounds) {
+        self.value.min(other)
+    }
+
+    fn max(&mut self, other: Self::Bounds) {
+        self.value.max(other)
+    }
+
+    fn upper_bound(&self) -> Option<Self::Bounds> {
+        self.value.upper_bound()
+    }
+
+    fn is_clamped(&self) -> bool {
+        self.value.is_clamped()
+    }
+
+    fn rebase(&mut self, old: &Self, new: &Self) {
+        self.value.rebase(&old.value, &new.value)
+    }
+
+    fn weighted_join(&mut self, other: Self, weight: Fraction<i32>) {
+        self.value.weighted_join(other.value, weight)
+    }
+
+    fn canonicalize(&mut self) {
+        self.value.canonicalize()
+    }
+
+    fn is_canonical(&self) -> bool {
+        self.value.is_canonical()
+    }
+
+    fn visit_eval(&self, f: &mut dyn FnMut(EvalValue)) {
+        f(EvalValue::Float(self.eval().to_f64()))
+    }
+
+    fn from_base(base: Self::Base) -> Self {
+        Self {
+            value: StatFloat::from_base(base),
+            rounding: R::default(),
+        }
+    }
+
+    fn into_base(self) -> Option<Self::Base> {
+        self.value.into_base()
+    }
+
+    fn simplify_ops(ops: Vec<StatOperation<Self>>) -> Vec<StatOperation<Self>> {
+        OpMerger {
+            add: Some(|a, b| a + b),
+            mul: Some(|a, b| a * b),
+            min: Some(Float::max),
+            max: Some(Float::min),
+            ..OpMerger::NONE
+        }
+        .simplify(ops)
+    }
+}
+
+/// A [`StatFloat`] that guards against `NaN` and infinities,
+/// so a single bad modifier does not corrupt the evaluated value.
+///
+/// `NaN` addends and evaluated values are replaced with `FALLBACK`,
+/// infinite addends are replaced with the stored bounds.
+/// Finite values are only clamped on [`StatValue::eval`], same as [`StatFloat`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Reflect)]
+#[repr(C, align(8))]
+pub struct StatFloatGuarded<T: Float, const FALLBACK: i64 = 0> {
+    addend: T,
+    min: T,
+    max: T,
+    mult: T,
+}
+
+impl<T: Float, const F: i64> StatFloatGuarded<T, F> {
+    /// Evaluate without clamping.
+    fn unclamped(&self) -> T {
+        self.guard(self.addend * self.mult)
+    }
+
+    /// Replace `NaN` with `FALLBACK` and infinities with the stored bounds.
+    fn guard(&self, value: T) -> T {
+        // Only true for `NaN`.
+        #[allow(clippy::eq_op)]
+        if value != value {
+            T::from_i64(F)
+        } else if value > T::MAX_VALUE {
+            self.max
+        } else if value < T::MIN_VALUE {
+            self.min
+        } else {
+            value
+        }
+    }
+}
+
+impl<T: Float, const F: i64> Default for StatFloatGuarded<T, F> {
+    fn default() -> Self {
+        Self {
+            addend: T::ZERO,
+            min: T::MIN_VALUE,
+            max: T::MAX_VALUE,
+            mult: T::ONE,
+        }
+    }
+}
+
+impl<T: Float, const F: i64> StatValue for StatFloatGuarded<T, F> {
+    type Out = T;
+    type Base = T;
+
+    fn join(&mut self, other: Self) {
+        self.addend = self.guard(self.addend + other.addend);
+        self.mult *= other.mult;
+        self.min = self.min.max(other.min);
+        self.max = self.max.min(other.max);
+    }
+
+    fn eval(&self) -> Self::Out {
+        self.unclamped().min(self.max).max(self.min)
+    }
+
+    type Add = T;
+    type Mul = T;
+    type Bounds = T;
+
+    type Bit = Unsupported;
+
+    fn add(&mut self, other: Self::Add) {
+        self.addend = self.guard(self.addend + other);
+    }
+
+    fn mul(&mut self, other: Self::Mul) {
+        self.mult *= other;
+    }
+
+    fn scale(&mut self, factor: Self::Mul) {
+        self.addend *= factor;
+    }
+
+    fn min(&mut self, other: Self::Bounds) {
+        self.min = self.min.max(other)
+    }
+
+    fn max(&mut self, other: Self::Bounds) {
+        self.max = self.max.min(other)
+    }
+
+    fn upper_bound(&self) -> Option<Self::Bounds> {
+        (self.max != Float::MAX_VALUE).then_some(self.max)
+    }
+
+    fn is_clamped(&self) -> bool {
+        is_clamped(self.unclamped(), self.min, self.max)
+    }
+
+    fn rebase(&mut self, old: &Self, new: &Self) {
+        rebase_addend(&mut self.addend, old.addend, new.addend)
+    }
+
+    fn weighted_join(&mut self, other: Self, weight: Fraction<i32>) {
+        let addend = lerp_float(self.addend, other.addend, weight);
+        self.join(other);
+        self.addend = addend;
+    }
+
+    fn canonicalize(&mut self) {
+        canonical_bounds(self.min, &mut self.max)
+    }
+
+    fn is_canonical(&self) -> bool {
+        self.min <= self.max
+    }
+
+    fn visit_eval(&self, f: &mut dyn FnMut(EvalValue)) {
+        f(EvalValue::Float(self.eval().to_f64()))
+    }
+
+    fn from_base(base: Self::Base) -> Self {
+        let mut result = Self::default();
+        result.addend = result.guard(base);
+        result
+    }
+
+    fn into_base(self) -> Option<Self::Base> {
+        Some(self.addend)
+    }
+
+    fn simplify_ops(ops: Vec<StatOperation<Self>>) -> Vec<StatOperation<Self>> {
+        OpMerger {
+            mul: Some(|a, b| a * b),
+            min: Some(Float::max),
+            max: Some(Float::min),
+            ..OpMerger::NONE
+        }
+        .simplify(ops)
+    }
 }
 
 /// A stat represented by a floating point number or a fraction, multiplier is additive.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TypePath)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Reflect)]
 #[repr(C, align(8))]
 pub struct StatFloatAdditive<T: Float> {
     addend: T,
@@ -92,6 +403,13 @@ impl<T: Float> Default for StatFloatAdditive<T> {
     }
 }
 
+impl<T: Float> StatFloatAdditive<T> {
+    /// Evaluate without clamping.
+    fn unclamped(&self) -> T {
+        self.addend * (self.mult + T::ONE)
+    }
+}
+
 impl<T: Float> StatValue for StatFloatAdditive<T> {
     type Out = T;
     type Base = T;
@@ -104,9 +422,7 @@ impl<T: Float> StatValue for StatFloatAdditive<T> {
     }
 
     fn eval(&self) -> Self::Out {
-        (self.addend * (self.mult + T::ONE))
-            .min(self.max)
-            .max(self.min)
+        self.unclamped().min(self.max).max(self.min)
     }
 
     type Add = T;
@@ -123,6 +439,10 @@ impl<T: Float> StatValue for StatFloatAdditive<T> {
         self.mult += other;
     }
 
+    fn scale(&mut self, factor: Self::Mul) {
+        self.addend *= factor;
+    }
+
     fn min(&mut self, other: Self::Bounds) {
         self.min = self.min.max(other)
     }
@@ -131,6 +451,36 @@ impl<T: Float> StatValue for StatFloatAdditive<T> {
         self.max = self.max.min(other)
     }
 
+    fn upper_bound(&self) -> Option<Self::Bounds> {
+        (self.max != Float::MAX_VALUE).then_some(self.max)
+    }
+
+    fn is_clamped(&self) -> bool {
+        is_clamped(self.unclamped(), self.min, self.max)
+    }
+
+    fn rebase(&mut self, old: &Self, new: &Self) {
+        rebase_addend(&mut self.addend, old.addend, new.addend)
+    }
+
+    fn weighted_join(&mut self, other: Self, weight: Fraction<i32>) {
+        let addend = lerp_float(self.addend, other.addend, weight);
+        self.join(other);
+        self.addend = addend;
+    }
+
+    fn canonicalize(&mut self) {
+        canonical_bounds(self.min, &mut self.max)
+    }
+
+    fn is_canonical(&self) -> bool {
+        self.min <= self.max
+    }
+
+    fn visit_eval(&self, f: &mut dyn FnMut(EvalValue)) {
+        f(EvalValue::Float(self.eval().to_f64()))
+    }
+
     fn from_base(base: Self::Base) -> Self {
         Self {
             addend: base,
@@ -139,10 +489,25 @@ impl<T: Float> StatValue for StatFloatAdditive<T> {
             mult: T::ZERO,
         }
     }
+
+    fn into_base(self) -> Option<Self::Base> {
+        Some(self.addend)
+    }
+
+    fn simplify_ops(ops: Vec<StatOperation<Self>>) -> Vec<StatOperation<Self>> {
+        OpMerger {
+            add: Some(|a, b| a + b),
+            mul: Some(|a, b| a + b),
+            min: Some(Float::max),
+            max: Some(Float::min),
+            ..OpMerger::NONE
+        }
+        .simplify(ops)
+    }
 }
 
 /// An floating point or fraction based multiplier aggregation. Does not support addition.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, TypePath)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Reflect)]
 #[repr(C, align(8))]
 pub struct StatMult<T: Float> {
     min: T,
@@ -194,6 +559,26 @@ impl<T: Float> StatValue for StatMult<T> {
         self.max = self.max.min(other);
     }
 
+    fn upper_bound(&self) -> Option<Self::Bounds> {
+        (self.max != Float::MAX_VALUE).then_some(self.max)
+    }
+
+    fn is_clamped(&self) -> bool {
+        is_clamped(self.mult, self.min, self.max)
+    }
+
+    fn canonicalize(&mut self) {
+        canonical_bounds(self.min, &mut self.max)
+    }
+
+    fn is_canonical(&self) -> bool {
+        self.min <= self.max
+    }
+
+    fn visit_eval(&self, f: &mut dyn FnMut(EvalValue)) {
+        f(EvalValue::Float(self.eval().to_f64()))
+    }
+
     fn from_base(base: Self::Base) -> Self {
         Self {
             min: T::MIN_VALUE,
@@ -201,4 +586,576 @@ impl<T: Float> StatValue for StatMult<T> {
             mult: base,
         }
     }
+
+    fn into_base(self) -> Option<Self::Base> {
+        Some(self.mult)
+    }
+
+    fn simplify_ops(ops: Vec<StatOperation<Self>>) -> Vec<StatOperation<Self>> {
+        OpMerger {
+            mul: Some(|a, b| a * b),
+            min: Some(Float::max),
+            max: Some(Float::min),
+            ..OpMerger::NONE
+        }
+        .simplify(ops)
+    }
+}
+
+/// A vector of stats represented by floating point numbers.
+///
+/// Addends are stored per component while the multiplier and bounds are shared
+/// scalars, so `mul`, `min` and `max` apply the same value to every component
+/// instead of working component-wise.
+///
+/// The whole vector must fit within the size limit of [`Stat::Value`](crate::Stat::Value),
+/// which in practice means `f32` with `N <= 3`, e.g. `StatVector<f32, 3>` for elemental resistances.
+/// Larger instantiations fail to compile when used.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Reflect)]
+#[repr(C, align(8))]
+pub struct StatVector<T: Float, const N: usize> {
+    #[serde(with = "array_serde")]
+    addend: [T; N],
+    mult: T,
+    min: T,
+    max: T,
+}
+
+mod array_serde {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<T: Serialize, S: Serializer, const N: usize>(
+        value: &[T; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(value)
+    }
+
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[T; N], D::Error> {
+        let vec = Vec::<T>::deserialize(deserializer)?;
+        let len = vec.len();
+        vec.try_into().map_err(|_| {
+            serde::de::Error::invalid_length(len, &format!("an array of length {N}").as_str())
+        })
+    }
+}
+
+impl<T: Float, const N: usize> Default for StatVector<T, N> {
+    fn default() -> Self {
+        const {
+            assert!(
+                std::mem::size_of::<Self>() <= 24,
+                "StatVector does not fit in a stat value, use `f32` with `N <= 3`."
+            )
+        };
+        Self {
+            addend: [T::ZERO; N],
+            mult: T::ONE,
+            min: T::MIN_VALUE,
+            max: T::MAX_VALUE,
+        }
+    }
+}
+
+impl<T: Float, const N: usize> StatValue for StatVector<T, N>
+where
+    [T; N]: Default,
+{
+    type Out = [T; N];
+    type Base = [T; N];
+
+    fn join(&mut self, other: Self) {
+        self.add(other.addend);
+        self.mult *= other.mult;
+        self.min = self.min.max(other.min);
+        self.max = self.max.min(other.max);
+    }
+
+    fn eval(&self) -> Self::Out {
+        self.addend
+            .map(|x| (x * self.mult).min(self.max).max(self.min))
+    }
+
+    type Add = [T; N];
+    type Mul = T;
+    type Bounds = T;
+
+    type Bit = Unsupported;
+
+    fn add(&mut self, other: Self::Add) {
+        for (a, b) in self.addend.iter_mut().zip(other) {
+            *a += b;
+        }
+    }
+
+    fn mul(&mut self, other: Self::Mul) {
+        self.mult *= other;
+    }
+
+    fn scale(&mut self, factor: Self::Mul) {
+        for x in &mut self.addend {
+            *x *= factor;
+        }
+    }
+
+    fn min(&mut self, other: Self::Bounds) {
+        self.min = self.min.max(other)
+    }
+
+    fn max(&mut self, other: Self::Bounds) {
+        self.max = self.max.min(other)
+    }
+
+    fn upper_bound(&self) -> Option<Self::Bounds> {
+        (self.max != Float::MAX_VALUE).then_some(self.max)
+    }
+
+    fn is_clamped(&self) -> bool {
+        self.addend
+            .iter()
+            .any(|x| is_clamped(*x * self.mult, self.min, self.max))
+    }
+
+    fn rebase(&mut self, old: &Self, new: &Self) {
+        for ((x, old), new) in self.addend.iter_mut().zip(old.addend).zip(new.addend) {
+            rebase_addend(x, old, new)
+        }
+    }
+
+    fn weighted_join(&mut self, other: Self, weight: Fraction<i32>) {
+        let mut addend = self.addend;
+        for (x, y) in addend.iter_mut().zip(other.addend) {
+            *x = lerp_float(*x, y, weight);
+        }
+        self.join(other);
+        self.addend = addend;
+    }
+
+    fn canonicalize(&mut self) {
+        canonical_bounds(self.min, &mut self.max)
+    }
+
+    fn is_canonical(&self) -> bool {
+        self.min <= self.max
+    }
+
+    fn from_base(base: Self::Base) -> Self {
+        Self {
+            addend: base,
+            ..Default::default()
+        }
+    }
+
+    fn into_base(self) -> Option<Self::Base> {
+        Some(self.addend)
+    }
+
+    fn simplify_ops(ops: Vec<StatOperation<Self>>) -> Vec<StatOperation<Self>> {
+        OpMerger {
+            add: Some(|mut a: [T; N], b| {
+                for (a, b) in a.iter_mut().zip(b) {
+                    *a += b;
+                }
+                a
+            }),
+            mul: Some(|a, b| a * b),
+            min: Some(Float::max),
+            max: Some(Float::min),
+            ..OpMerger::NONE
+        }
+        .simplify(ops)
+    }
+}
+
+/// A stat with separate flat, additive percentage and multiplicative tracks,
+/// and a hard cap applied after everything else.
+///
+/// # Evaluation order
+///
+/// 1. Sum all flat addends and clamp the sum by `min` and `max`.
+/// 2. Multiply by `1 + percent`, where `percent` is the sum of all percentages.
+/// 3. Multiply by the product of all multipliers.
+/// 4. Clamp the result to be no greater than `cap`.
+///
+/// Unlike `max`, `cap` cannot be exceeded by any multiplier, or by `min`.
+///
+/// [`StatValue::add`] and [`StatValue::mul`] write to the flat and multiplicative tracks,
+/// use [`StatComposite::add_percent`] and [`StatComposite::cap`] for the others.
+///
+/// Only fits in a stat value if `T` is at most 4 bytes, e.g. `f32`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[repr(C, align(8))]
+pub struct StatComposite<T: Float> {
+    addend: T,
+    percent: T,
+    mult: T,
+    min: T,
+    max: T,
+    cap: T,
+}
+
+impl<T: Float> Default for StatComposite<T> {
+    fn default() -> Self {
+        const {
+            assert!(
+                std::mem::size_of::<Self>() <= 24,
+                "StatComposite does not fit in a stat value, use `f32`."
+            )
+        };
+        Self {
+            addend: T::ZERO,
+            percent: T::ZERO,
+            mult: T::ONE,
+            min: T::MIN_VALUE,
+            max: T::MAX_VALUE,
+            cap: T::MAX_VALUE,
+        }
+    }
+}
+
+impl<T: Float> StatComposite<T> {
+    /// Add to the additive percentage track, `0.5` means `+50%`.
+    pub fn add_percent(&mut self, percent: T) {
+        self.percent += percent;
+    }
+
+    /// Set a hard cap on the evaluated value, the lowest cap wins.
+    pub fn cap(&mut self, cap: T) {
+        self.cap = self.cap.min(cap);
+    }
+
+    /// Add to the additive percentage track, see [`StatComposite::add_percent`].
+    pub fn with_percent(mut self, percent: T) -> Self {
+        self.add_percent(percent);
+        self
+    }
+
+    /// Set a hard cap on the evaluated value, see [`StatComposite::cap`].
+    pub fn with_cap(mut self, cap: T) -> Self {
+        self.cap(cap);
+        self
+    }
+
+    /// Evaluate without the hard cap.
+    fn uncapped(&self) -> T {
+        self.addend.min(self.max).max(self.min) * (self.percent + T::ONE) * self.mult
+    }
+}
+
+impl<T: Float> StatValue for StatComposite<T> {
+    type Out = T;
+    type Base = T;
+
+    fn join(&mut self, other: Self) {
+        self.addend += other.addend;
+        self.percent += other.percent;
+        self.mult *= other.mult;
+        self.min = self.min.max(other.min);
+        self.max = self.max.min(other.max);
+        self.cap = self.cap.min(other.cap);
+    }
+
+    fn eval(&self) -> Self::Out {
+        self.uncapped().min(self.cap)
+    }
+
+    type Add = T;
+    type Mul = T;
+    type Bounds = T;
+
+    type Bit = Unsupported;
+
+    fn add(&mut self, other: Self::Add) {
+        self.addend += other;
+    }
+
+    fn mul(&mut self, other: Self::Mul) {
+        self.mult *= other;
+    }
+
+    fn scale(&mut self, factor: Self::Mul) {
+        self.addend *= factor;
+    }
+
+    fn min(&mut self, other: Self::Bounds) {
+        self.min = self.min.max(other)
+    }
+
+    fn max(&mut self, other: Self::Bounds) {
+        self.max = self.max.min(other)
+    }
+
+    fn upper_bound(&self) -> Option<Self::Bounds> {
+        (self.cap != Float::MAX_VALUE).then_some(self.cap)
+    }
+
+    fn is_clamped(&self) -> bool {
+        is_clamped(self.addend, self.min, self.max)
+            || is_clamped(self.uncapped(), T::MIN_VALUE, self.cap)
+    }
+
+    fn rebase(&mut self, old: &Self, new: &Self) {
+        rebase_addend(&mut self.addend, old.addend, new.addend)
+    }
+
+    fn weighted_join(&mut self, other: Self, weight: Fraction<i32>) {
+        let addend = lerp_float(self.addend, other.addend, weight);
+        self.join(other);
+        self.addend = addend;
+    }
+
+    fn canonicalize(&mut self) {
+        canonical_bounds(self.min, &mut self.max)
+    }
+
+    fn is_canonical(&self) -> bool {
+        self.min <= self.max
+    }
+
+    fn visit_eval(&self, f: &mut dyn FnMut(EvalValue)) {
+        f(EvalValue::Float(self.eval().to_f64()))
+    }
+
+    fn from_base(base: Self::Base) -> Self {
+        Self {
+            addend: base,
+            ..Default::default()
+        }
+    }
+
+    fn into_base(self) -> Option<Self::Base> {
+        Some(self.addend)
+    }
+
+    fn simplify_ops(ops: Vec<StatOperation<Self>>) -> Vec<StatOperation<Self>> {
+        OpMerger {
+            add: Some(|a, b| a + b),
+            mul: Some(|a, b| a * b),
+            min: Some(Float::max),
+            max: Some(Float::min),
+            ..OpMerger::NONE
+        }
+        .simplify(ops)
+    }
+}
+
+/// A stat using the common RPG formula `(base + flat) * (1 + increase) * more`.
+///
+/// # Evaluation order
+///
+/// 1. Add the sum of all flat bonuses to the sum of all bases.
+/// 2. Multiply by `1 + increase`, where `increase` is the sum of all increases.
+/// 3. Multiply by the product of all `more` multipliers.
+/// 4. Clamp the result by `min` and `max`.
+///
+/// [`StatValue::from_base`] sets the base, [`StatValue::add`] and [`StatValue::mul`]
+/// write to the flat and `more` layers, use [`StatLayered::increase`] for the increase layer.
+///
+/// Only fits in a stat value if `T` is at most 4 bytes, e.g. `f32`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[repr(C, align(8))]
+pub struct StatLayered<T: Float> {
+    base: T,
+    flat: T,
+    increase: T,
+    more: T,
+    min: T,
+    max: T,
+}
+
+impl<T: Float> Default for StatLayered<T> {
+    fn default() -> Self {
+        const {
+            assert!(
+                std::mem::size_of::<Self>() <= 24,
+                "StatLayered does not fit in a stat value, use `f32`."
+            )
+        };
+        Self {
+            base: T::ZERO,
+            flat: T::ZERO,
+            increase: T::ZERO,
+            more: T::ONE,
+            min: T::MIN_VALUE,
+            max: T::MAX_VALUE,
+        }
+    }
+}
+
+impl<T: Float> StatLayered<T> {
+    /// Add to the summed increase layer, `0.5` means `50% increased`.
+    pub fn increase(&mut self, increase: T) {
+        self.increase += increase;
+    }
+
+    /// Add to the summed increase layer, see [`StatLayered::increase`].
+    pub fn with_increase(mut self, increase: T) -> Self {
+        self.increase(increase);
+        self
+    }
+
+    /// Evaluate without clamping.
+    fn unclamped(&self) -> T {
+        (self.base + self.flat) * (self.increase + T::ONE) * self.more
+    }
+}
+
+impl<T: Float> StatValue for StatLayered<T> {
+    type Out = T;
+    type Base = T;
+
+    fn join(&mut self, other: Self) {
+        self.base += other.base;
+        self.flat += other.flat;
+        self.increase += other.increase;
+        self.more *= other.more;
+        self.min = self.min.max(other.min);
+        self.max = self.max.min(other.max);
+    }
+
+    fn eval(&self) -> Self::Out {
+        self.unclamped().min(self.max).max(self.min)
+    }
+
+    type Add = T;
+    type Mul = T;
+    type Bounds = T;
+
+    type Bit = Unsupported;
+
+    fn add(&mut self, other: Self::Add) {
+        self.flat += other;
+    }
+
+    fn mul(&mut self, other: Self::Mul) {
+        self.more *= other;
+    }
+
+    fn scale(&mut self, factor: Self::Mul) {
+        self.base *= factor;
+        self.flat *= factor;
+    }
+
+    fn min(&mut self, other: Self::Bounds) {
+        self.min = self.min.max(other)
+    }
+
+    fn max(&mut self, other: Self::Bounds) {
+        self.max = self.max.min(other)
+    }
+
+    fn upper_bound(&self) -> Option<Self::Bounds> {
+        (self.max != Float::MAX_VALUE).then_some(self.max)
+    }
+
+    fn is_clamped(&self) -> bool {
+        is_clamped(self.unclamped(), self.min, self.max)
+    }
+
+    fn rebase(&mut self, old: &Self, new: &Self) {
+        rebase_addend(&mut self.base, old.base, new.base)
+    }
+
+    fn canonicalize(&mut self) {
+        canonical_bounds(self.min, &mut self.max)
+    }
+
+    fn is_canonical(&self) -> bool {
+        self.min <= self.max
+    }
+
+    fn visit_eval(&self, f: &mut dyn FnMut(EvalValue)) {
+        f(EvalValue::Float(self.eval().to_f64()))
+    }
+
+    fn from_base(base: Self::Base) -> Self {
+        Self {
+            base,
+            ..Default::default()
+        }
+    }
+
+    fn into_base(self) -> Option<Self::Base> {
+        Some(self.base)
+    }
+
+    fn simplify_ops(ops: Vec<StatOperation<Self>>) -> Vec<StatOperation<Self>> {
+        OpMerger {
+            add: Some(|a, b| a + b),
+            mul: Some(|a, b| a * b),
+            min: Some(Float::max),
+            max: Some(Float::min),
+            ..OpMerger::NONE
+        }
+        .simplify(ops)
+    }
+}
+
+/// A weighted mean of all contributions.
+///
+/// [`StatValue::add`] takes a `(value, weight)` pair, [`StatValue::from_base`] has weight `1`.
+/// Evaluates to `sum(value * weight) / sum(weight)`, or `0` if the total weight is `0`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Reflect)]
+#[repr(C, align(8))]
+pub struct StatWeightedMean<T: Float> {
+    sum_weighted: T,
+    sum_weights: T,
+}
+
+impl<T: Float> Default for StatWeightedMean<T> {
+    fn default() -> Self {
+        Self {
+            sum_weighted: T::ZERO,
+            sum_weights: T::ZERO,
+        }
+    }
+}
+
+impl<T: Float> StatWeightedMean<T> {
+    /// Returns the total weight of all contributions.
+    pub fn total_weight(&self) -> T {
+        self.sum_weights
+    }
+}
+
+impl<T: Float> StatValue for StatWeightedMean<T> {
+    type Out = T;
+    type Base = T;
+
+    fn join(&mut self, other: Self) {
+        self.sum_weighted += other.sum_weighted;
+        self.sum_weights += other.sum_weights;
+    }
+
+    fn eval(&self) -> Self::Out {
+        if self.sum_weights == T::ZERO {
+            T::ZERO
+        } else {
+            self.sum_weighted / self.sum_weights
+        }
+    }
+
+    type Add = (T, T);
+    type Mul = Unsupported;
+    type Bounds = Unsupported;
+
+    type Bit = Unsupported;
+
+    fn add(&mut self, (value, weight): Self::Add) {
+        self.sum_weighted += value * weight;
+        self.sum_weights += weight;
+    }
+
+    fn visit_eval(&self, f: &mut dyn FnMut(EvalValue)) {
+        f(EvalValue::Float(self.eval().to_f64()))
+    }
+
+    fn from_base(base: Self::Base) -> Self {
+        Self {
+            sum_weighted: base,
+            sum_weights: T::ONE,
+        }
+    }
 }
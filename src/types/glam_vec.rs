@@ -0,0 +1,80 @@
+use super::rebase_addend;
+use crate::{operations::Unsupported, StatValue};
+use bevy_reflect::Reflect;
+use glam::{Vec2, Vec3};
+use serde::{Deserialize, Serialize};
+
+macro_rules! impl_stat_vec {
+    ($($(#[$($attr: tt)*])* $name: ident: $vec: ty),* $(,)?) => {
+        $($(#[$($attr)*])*
+        #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Reflect)]
+        #[repr(C, align(8))]
+        pub struct $name {
+            addend: $vec,
+            mult: f32,
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self {
+                    addend: <$vec>::ZERO,
+                    mult: 1.0,
+                }
+            }
+        }
+
+        impl StatValue for $name {
+            type Out = $vec;
+            type Base = $vec;
+
+            fn join(&mut self, other: Self) {
+                self.addend += other.addend;
+                self.mult *= other.mult;
+            }
+
+            fn eval(&self) -> Self::Out {
+                self.addend * self.mult
+            }
+
+            type Add = $vec;
+            type Mul = f32;
+            type Bounds = Unsupported;
+
+            type Bit = Unsupported;
+
+            fn add(&mut self, other: Self::Add) {
+                self.addend += other;
+            }
+
+            fn mul(&mut self, other: Self::Mul) {
+                self.mult *= other;
+            }
+
+            fn scale(&mut self, factor: Self::Mul) {
+                self.addend *= factor;
+            }
+
+            fn rebase(&mut self, old: &Self, new: &Self) {
+                rebase_addend(&mut self.addend, old.addend, new.addend)
+            }
+
+            fn from_base(base: Self::Base) -> Self {
+                Self {
+                    addend: base,
+                    mult: 1.0,
+                }
+            }
+
+            fn into_base(self) -> Option<Self::Base> {
+                Some(self.addend)
+            }
+        })*
+    };
+}
+
+impl_stat_vec!(
+    /// A [`Vec2`] stat, adds component-wise and multiplies by a scalar.
+    StatVec2: Vec2,
+    /// A [`Vec3`] stat, adds component-wise and multiplies by a scalar.
+    StatVec3: Vec3,
+);
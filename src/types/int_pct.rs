@@ -1,23 +1,28 @@
+use super::{canonical_bounds, is_clamped, rebase_addend};
+use crate::num_traits::lerp_int;
 use crate::Fraction;
-use crate::{operations::Unsupported, StatValue};
+use crate::{
+    operations::{OpMerger, StatOperation, Unsupported},
+    EvalValue, StatValue,
+};
 use crate::{
     rounding::{Rounding, Truncate},
     Float, Int,
 };
-use bevy_reflect::TypePath;
+use bevy_reflect::Reflect;
 use serde::{Deserialize, Serialize};
-use std::marker::PhantomData;
 
 /// An integer stat that sums up multipliers additively,
 /// then divided by `SCALE`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TypePath)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Reflect)]
 #[repr(C, align(8))]
 pub struct StatIntPercentAdditive<T: Int, R: Rounding = Truncate, const SCALE: i64 = 100> {
     addend: T,
     mult: T,
     min: T,
     max: T,
-    rounding: PhantomData<R>,
+    #[reflect(ignore)]
+    rounding: R,
 }
 
 impl<T: Int, R: Rounding, const S: i64> Default for StatIntPercentAdditive<T, R, S> {
@@ -27,11 +32,25 @@ impl<T: Int, R: Rounding, const S: i64> Default for StatIntPercentAdditive<T, R,
             min: T::MIN_VALUE,
             max: T::MAX_VALUE,
             mult: T::ZERO,
-            rounding: PhantomData,
+            rounding: R::default(),
         }
     }
 }
 
+impl<T: Int, R: Rounding, const S: i64> StatIntPercentAdditive<T, R, S> {
+    /// Set the [`Rounding`] method, e.g. a [`DynRounding`](crate::rounding::DynRounding) chosen at runtime.
+    pub fn with_rounding(mut self, rounding: R) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Evaluate without clamping.
+    fn unclamped(&self) -> T {
+        let numer = self.addend * (self.mult + T::from_i64(S));
+        T::from_fraction(self.rounding.round(numer.build_fraction(T::from_i64(S))))
+    }
+}
+
 impl<T: Int, R: Rounding, const S: i64> StatValue for StatIntPercentAdditive<T, R, S> {
     type Out = T;
     type Base = T;
@@ -44,9 +63,7 @@ impl<T: Int, R: Rounding, const S: i64> StatValue for StatIntPercentAdditive<T,
     }
 
     fn eval(&self) -> Self::Out {
-        let numer = self.addend * (self.mult + T::from_i64(S));
-        let base = T::from_fraction(R::round(numer.build_fraction(T::from_i64(S))));
-        base.min(self.max).max(self.min)
+        self.unclamped().min(self.max).max(self.min)
     }
 
     type Add = T;
@@ -64,6 +81,10 @@ impl<T: Int, R: Rounding, const S: i64> StatValue for StatIntPercentAdditive<T,
         self.mult += other;
     }
 
+    fn scale(&mut self, factor: Self::Mul) {
+        self.addend = scale_percent(self.addend, factor, S, self.rounding);
+    }
+
     fn min(&mut self, other: Self::Bounds) {
         self.min = self.min.max(other)
     }
@@ -72,30 +93,78 @@ impl<T: Int, R: Rounding, const S: i64> StatValue for StatIntPercentAdditive<T,
         self.max = self.max.min(other)
     }
 
+    fn upper_bound(&self) -> Option<Self::Bounds> {
+        (self.max != Int::MAX_VALUE).then_some(self.max)
+    }
+
+    fn is_clamped(&self) -> bool {
+        is_clamped(self.unclamped(), self.min, self.max)
+    }
+
+    fn rebase(&mut self, old: &Self, new: &Self) {
+        rebase_addend(&mut self.addend, old.addend, new.addend)
+    }
+
+    fn weighted_join(&mut self, other: Self, weight: Fraction<i32>) {
+        let addend = lerp_int(self.addend, other.addend, weight);
+        self.join(other);
+        self.addend = addend;
+    }
+
+    fn canonicalize(&mut self) {
+        canonical_bounds(self.min, &mut self.max)
+    }
+
+    fn is_canonical(&self) -> bool {
+        self.min <= self.max
+    }
+
+    fn visit_eval(&self, f: &mut dyn FnMut(EvalValue)) {
+        f(EvalValue::Int(self.eval().to_i64()))
+    }
+
     fn from_base(base: Self::Base) -> Self {
         Self {
             addend: base,
             min: T::MIN_VALUE,
             max: T::MAX_VALUE,
             mult: T::ZERO,
-            rounding: PhantomData,
+            rounding: R::default(),
         }
     }
+
+    fn into_base(self) -> Option<Self::Base> {
+        Some(self.addend)
+    }
+
+    fn simplify_ops(ops: Vec<StatOperation<Self>>) -> Vec<StatOperation<Self>> {
+        OpMerger {
+            add: Some(|a, b| a + b),
+            mul: Some(|a, b| a + b),
+            min: Some(Int::max),
+            max: Some(Int::min),
+            ..OpMerger::NONE
+        }
+        .simplify(ops)
+    }
 }
 
 /// An integer stat with integer multipliers divided by `SCALE`.
 ///
 /// Calculated as a fraction.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TypePath)]
-#[serde(bound(serialize = "T: Int<PrimInt: Serialize> + Serialize, R: Rounding"))]
-#[serde(bound(deserialize = "T: Int<PrimInt: Deserialize<'de>> + Deserialize<'de>, R: Rounding"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[serde(bound(serialize = "T: Int<PrimInt: Serialize> + Serialize, R: Rounding + Serialize"))]
+#[serde(bound(
+    deserialize = "T: Int<PrimInt: Deserialize<'de>> + Deserialize<'de>, R: Rounding + Deserialize<'de>"
+))]
 #[repr(C, align(8))]
 pub struct StatIntPercent<T: Int, R: Rounding = Truncate, const SCALE: i64 = 100> {
     addend: T,
     mult: Fraction<T::PrimInt>,
     min: T,
     max: T,
-    rounding: PhantomData<R>,
+    #[reflect(ignore)]
+    rounding: R,
 }
 
 impl<T: Int, R: Rounding, const S: i64> Default for StatIntPercent<T, R, S> {
@@ -105,11 +174,24 @@ impl<T: Int, R: Rounding, const S: i64> Default for StatIntPercent<T, R, S> {
             min: T::MIN_VALUE,
             max: T::MAX_VALUE,
             mult: Float::ONE,
-            rounding: PhantomData,
+            rounding: R::default(),
         }
     }
 }
 
+impl<T: Int, R: Rounding, const S: i64> StatIntPercent<T, R, S> {
+    /// Set the [`Rounding`] method, e.g. a [`DynRounding`](crate::rounding::DynRounding) chosen at runtime.
+    pub fn with_rounding(mut self, rounding: R) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Evaluate without clamping.
+    fn unclamped(&self) -> T {
+        T::from_fraction(self.rounding.round(self.addend.into_fraction() * self.mult))
+    }
+}
+
 impl<T: Int, R: Rounding, const S: i64> StatValue for StatIntPercent<T, R, S> {
     type Out = T;
     type Base = T;
@@ -122,9 +204,7 @@ impl<T: Int, R: Rounding, const S: i64> StatValue for StatIntPercent<T, R, S> {
     }
 
     fn eval(&self) -> Self::Out {
-        let fraction = self.addend.into_fraction() * self.mult;
-        let int = T::from_fraction(R::round(fraction));
-        int.min(self.max).max(self.min)
+        self.unclamped().min(self.max).max(self.min)
     }
 
     type Add = T;
@@ -141,6 +221,10 @@ impl<T: Int, R: Rounding, const S: i64> StatValue for StatIntPercent<T, R, S> {
         self.mult *= T::build_fraction(other, T::from_i64(S));
     }
 
+    fn scale(&mut self, factor: Self::Mul) {
+        self.addend = scale_percent(self.addend, factor, S, self.rounding);
+    }
+
     fn min(&mut self, other: Self::Bounds) {
         self.min = self.min.max(other)
     }
@@ -149,13 +233,186 @@ impl<T: Int, R: Rounding, const S: i64> StatValue for StatIntPercent<T, R, S> {
         self.max = self.max.min(other)
     }
 
+    fn upper_bound(&self) -> Option<Self::Bounds> {
+        (self.max != Int::MAX_VALUE).then_some(self.max)
+    }
+
+    fn is_clamped(&self) -> bool {
+        is_clamped(self.unclamped(), self.min, self.max)
+    }
+
+    fn rebase(&mut self, old: &Self, new: &Self) {
+        rebase_addend(&mut self.addend, old.addend, new.addend)
+    }
+
+    fn weighted_join(&mut self, other: Self, weight: Fraction<i32>) {
+        let addend = lerp_int(self.addend, other.addend, weight);
+        self.join(other);
+        self.addend = addend;
+    }
+
+    fn canonicalize(&mut self) {
+        canonical_bounds(self.min, &mut self.max)
+    }
+
+    fn is_canonical(&self) -> bool {
+        self.min <= self.max
+    }
+
+    fn visit_eval(&self, f: &mut dyn FnMut(EvalValue)) {
+        f(EvalValue::Int(self.eval().to_i64()))
+    }
+
     fn from_base(base: Self::Base) -> Self {
         Self {
             addend: base,
             min: T::MIN_VALUE,
             max: T::MAX_VALUE,
             mult: Float::ONE,
-            rounding: PhantomData,
+            rounding: R::default(),
+        }
+    }
+
+    fn into_base(self) -> Option<Self::Base> {
+        Some(self.addend)
+    }
+
+    fn simplify_ops(ops: Vec<StatOperation<Self>>) -> Vec<StatOperation<Self>> {
+        OpMerger {
+            add: Some(|a, b| a + b),
+            min: Some(Int::max),
+            max: Some(Int::min),
+            ..OpMerger::NONE
+        }
+        .simplify(ops)
+    }
+}
+
+/// Multiply an [`Int`] by `factor / scale`, rounded by `rounding`.
+fn scale_percent<T: Int>(value: T, factor: T, scale: i64, rounding: impl Rounding) -> T {
+    T::from_fraction(rounding.round((value * factor).build_fraction(T::from_i64(scale))))
+}
+
+/// Add two [`Int`]s, saturating at the bounds of `T` instead of overflowing.
+fn saturating_add<T: Int>(a: T, b: T) -> T {
+    T::from_i64(a.to_i64().saturating_add(b.to_i64()))
+}
+
+/// An integer stat that sums up multipliers additively, then divided by `SCALE`,
+/// same as [`StatIntPercentAdditive`], but saturates at the bounds of `T` instead of overflowing.
+///
+/// Intermediate values are computed as `i128`, the result is truncated towards zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[repr(C, align(8))]
+pub struct StatPercentSaturating<T: Int, const SCALE: i64 = 100> {
+    addend: T,
+    mult: T,
+    min: T,
+    max: T,
+}
+
+impl<T: Int, const S: i64> Default for StatPercentSaturating<T, S> {
+    fn default() -> Self {
+        Self {
+            addend: T::ZERO,
+            min: T::MIN_VALUE,
+            max: T::MAX_VALUE,
+            mult: T::ZERO,
+        }
+    }
+}
+
+impl<T: Int, const S: i64> StatPercentSaturating<T, S> {
+    /// Evaluate without clamping by `min` and `max`.
+    fn unclamped(&self) -> T {
+        let numer = self.addend.to_i64() as i128 * (self.mult.to_i64() as i128 + S as i128);
+        let value = numer / S as i128;
+        T::from_i64(value.clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+    }
+}
+
+impl<T: Int, const S: i64> StatValue for StatPercentSaturating<T, S> {
+    type Out = T;
+    type Base = T;
+
+    fn join(&mut self, other: Self) {
+        self.addend = saturating_add(self.addend, other.addend);
+        self.mult = saturating_add(self.mult, other.mult);
+        self.max = self.max.min(other.max);
+        self.min = self.min.max(other.min);
+    }
+
+    fn eval(&self) -> Self::Out {
+        self.unclamped().min(self.max).max(self.min)
+    }
+
+    type Add = T;
+    type Mul = T;
+    type Bounds = T;
+
+    type Bit = Unsupported;
+
+    fn add(&mut self, other: Self::Add) {
+        self.addend = saturating_add(self.addend, other);
+    }
+
+    fn mul(&mut self, other: Self::Mul) {
+        self.mult = saturating_add(self.mult, other);
+    }
+
+    fn scale(&mut self, factor: Self::Mul) {
+        let numer = self.addend.to_i64() as i128 * factor.to_i64() as i128;
+        let value = numer / S as i128;
+        self.addend = T::from_i64(value.clamp(i64::MIN as i128, i64::MAX as i128) as i64);
+    }
+
+    fn min(&mut self, other: Self::Bounds) {
+        self.min = self.min.max(other)
+    }
+
+    fn max(&mut self, other: Self::Bounds) {
+        self.max = self.max.min(other)
+    }
+
+    fn upper_bound(&self) -> Option<Self::Bounds> {
+        (self.max != Int::MAX_VALUE).then_some(self.max)
+    }
+
+    fn is_clamped(&self) -> bool {
+        is_clamped(self.unclamped(), self.min, self.max)
+    }
+
+    fn canonicalize(&mut self) {
+        canonical_bounds(self.min, &mut self.max)
+    }
+
+    fn is_canonical(&self) -> bool {
+        self.min <= self.max
+    }
+
+    fn visit_eval(&self, f: &mut dyn FnMut(EvalValue)) {
+        f(EvalValue::Int(self.eval().to_i64()))
+    }
+
+    fn from_base(base: Self::Base) -> Self {
+        Self {
+            addend: base,
+            ..Default::default()
+        }
+    }
+
+    fn into_base(self) -> Option<Self::Base> {
+        Some(self.addend)
+    }
+
+    fn simplify_ops(ops: Vec<StatOperation<Self>>) -> Vec<StatOperation<Self>> {
+        OpMerger {
+            add: Some(saturating_add),
+            mul: Some(saturating_add),
+            min: Some(Int::max),
+            max: Some(Int::min),
+            ..OpMerger::NONE
         }
+        .simplify(ops)
     }
 }
@@ -1,15 +1,19 @@
-use crate::{operations::Unsupported, StatValue};
+use super::{canonical_bounds, is_clamped, rebase_addend};
+use crate::num_traits::lerp_int;
+use crate::{
+    operations::{OpMerger, StatOperation, Unsupported},
+    EvalValue, StatValue,
+};
 use crate::{
     rounding::{Rounding, Truncate},
-    Float, Int,
+    Float, Fraction, Int,
 };
-use bevy_reflect::TypePath;
+use bevy_reflect::Reflect;
 use num_traits::AsPrimitive;
 use serde::{Deserialize, Serialize};
-use std::marker::PhantomData;
 
 /// A stat represented by an integer, does not support floating point multipliers.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TypePath)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Reflect)]
 #[repr(C, align(8))]
 pub struct StatInt<T: Int> {
     addend: T,
@@ -29,6 +33,13 @@ impl<T: Int> Default for StatInt<T> {
     }
 }
 
+impl<T: Int> StatInt<T> {
+    /// Evaluate without clamping.
+    fn unclamped(&self) -> T {
+        self.addend * self.mult
+    }
+}
+
 impl<T: Int> StatValue for StatInt<T> {
     type Out = T;
     type Base = T;
@@ -40,8 +51,24 @@ impl<T: Int> StatValue for StatInt<T> {
         self.max = self.max.min(other.max);
     }
 
+    fn join_many(&mut self, others: impl Iterator<Item = Self>) {
+        let (mut addend, mut mult, mut min, mut max) = (self.addend, self.mult, self.min, self.max);
+        for other in others {
+            addend += other.addend;
+            mult *= other.mult;
+            min = min.max(other.min);
+            max = max.min(other.max);
+        }
+        *self = Self {
+            addend,
+            mult,
+            min,
+            max,
+        };
+    }
+
     fn eval(&self) -> Self::Out {
-        (self.addend * self.mult).min(self.max).max(self.min)
+        self.unclamped().min(self.max).max(self.min)
     }
 
     type Add = T;
@@ -58,6 +85,10 @@ impl<T: Int> StatValue for StatInt<T> {
         self.mult *= other;
     }
 
+    fn scale(&mut self, factor: Self::Mul) {
+        self.addend *= factor;
+    }
+
     fn min(&mut self, other: Self::Bounds) {
         self.min = self.min.max(other)
     }
@@ -66,6 +97,36 @@ impl<T: Int> StatValue for StatInt<T> {
         self.max = self.max.min(other)
     }
 
+    fn upper_bound(&self) -> Option<Self::Bounds> {
+        (self.max != Int::MAX_VALUE).then_some(self.max)
+    }
+
+    fn is_clamped(&self) -> bool {
+        is_clamped(self.unclamped(), self.min, self.max)
+    }
+
+    fn rebase(&mut self, old: &Self, new: &Self) {
+        rebase_addend(&mut self.addend, old.addend, new.addend)
+    }
+
+    fn weighted_join(&mut self, other: Self, weight: Fraction<i32>) {
+        let addend = lerp_int(self.addend, other.addend, weight);
+        self.join(other);
+        self.addend = addend;
+    }
+
+    fn canonicalize(&mut self) {
+        canonical_bounds(self.min, &mut self.max)
+    }
+
+    fn is_canonical(&self) -> bool {
+        self.min <= self.max
+    }
+
+    fn visit_eval(&self, f: &mut dyn FnMut(EvalValue)) {
+        f(EvalValue::Int(self.eval().to_i64()))
+    }
+
     fn from_base(base: Self::Base) -> Self {
         Self {
             addend: base,
@@ -74,17 +135,33 @@ impl<T: Int> StatValue for StatInt<T> {
             mult: T::ONE,
         }
     }
+
+    fn into_base(self) -> Option<Self::Base> {
+        Some(self.addend)
+    }
+
+    fn simplify_ops(ops: Vec<StatOperation<Self>>) -> Vec<StatOperation<Self>> {
+        OpMerger {
+            add: Some(|a, b| a + b),
+            mul: Some(|a, b| a * b),
+            min: Some(Int::max),
+            max: Some(Int::min),
+            ..OpMerger::NONE
+        }
+        .simplify(ops)
+    }
 }
 
 /// An integer stat that multiplies with floating point numbers and rounds back to an integer.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, TypePath, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Serialize, Deserialize)]
 #[repr(C, align(8))]
 pub struct StatIntRounded<T: Int, F: Float, R: Rounding = Truncate> {
     addend: T,
     min: T,
     max: T,
     mult: F,
-    rounding: PhantomData<R>,
+    #[reflect(ignore)]
+    rounding: R,
 }
 
 impl<T: Int, F: Float, R: Rounding> Default for StatIntRounded<T, F, R> {
@@ -94,11 +171,29 @@ impl<T: Int, F: Float, R: Rounding> Default for StatIntRounded<T, F, R> {
             min: T::MIN_VALUE,
             max: T::MAX_VALUE,
             mult: F::ONE,
-            rounding: Default::default(),
+            rounding: R::default(),
         }
     }
 }
 
+impl<T: Int, F: Float, R: Rounding> StatIntRounded<T, F, R>
+where
+    T: AsPrimitive<F>,
+    F: AsPrimitive<T>,
+{
+    /// Set the [`Rounding`] method, e.g. a [`DynRounding`](crate::rounding::DynRounding) chosen at runtime.
+    pub fn with_rounding(mut self, rounding: R) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Evaluate without clamping.
+    fn unclamped(&self) -> T {
+        let value = self.addend.as_() * self.mult;
+        self.rounding.round(value).as_()
+    }
+}
+
 impl<T: Int, F: Float, R: Rounding> StatValue for StatIntRounded<T, F, R>
 where
     T: AsPrimitive<F>,
@@ -115,9 +210,7 @@ where
     }
 
     fn eval(&self) -> Self::Out {
-        let val = self.addend.as_() * self.mult;
-        let int_val: T = R::round(val).as_();
-        int_val.min(self.max).max(self.min)
+        self.unclamped().min(self.max).max(self.min)
     }
 
     type Add = T;
@@ -134,6 +227,10 @@ where
         self.mult *= other;
     }
 
+    fn scale(&mut self, factor: Self::Mul) {
+        self.addend = self.rounding.round(self.addend.as_() * factor).as_();
+    }
+
     fn min(&mut self, other: Self::Bounds) {
         self.min = self.min.max(other);
     }
@@ -142,13 +239,58 @@ where
         self.max = self.max.min(other);
     }
 
+    fn upper_bound(&self) -> Option<Self::Bounds> {
+        (self.max != Int::MAX_VALUE).then_some(self.max)
+    }
+
+    fn is_clamped(&self) -> bool {
+        is_clamped(self.unclamped(), self.min, self.max)
+    }
+
+    fn rebase(&mut self, old: &Self, new: &Self) {
+        rebase_addend(&mut self.addend, old.addend, new.addend)
+    }
+
+    fn weighted_join(&mut self, other: Self, weight: Fraction<i32>) {
+        let addend = lerp_int(self.addend, other.addend, weight);
+        self.join(other);
+        self.addend = addend;
+    }
+
+    fn canonicalize(&mut self) {
+        canonical_bounds(self.min, &mut self.max)
+    }
+
+    fn is_canonical(&self) -> bool {
+        self.min <= self.max
+    }
+
+    fn visit_eval(&self, f: &mut dyn FnMut(EvalValue)) {
+        f(EvalValue::Int(self.eval().to_i64()))
+    }
+
     fn from_base(base: Self::Base) -> Self {
         Self {
             addend: base,
             min: T::MIN_VALUE,
             max: T::MAX_VALUE,
             mult: Float::ONE,
-            rounding: Default::default(),
+            rounding: R::default(),
+        }
+    }
+
+    fn into_base(self) -> Option<Self::Base> {
+        Some(self.addend)
+    }
+
+    fn simplify_ops(ops: Vec<StatOperation<Self>>) -> Vec<StatOperation<Self>> {
+        OpMerger {
+            add: Some(|a, b| a + b),
+            mul: Some(|a, b| a * b),
+            min: Some(Int::max),
+            max: Some(Int::min),
+            ..OpMerger::NONE
         }
+        .simplify(ops)
     }
 }
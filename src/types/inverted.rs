@@ -0,0 +1,105 @@
+use crate::{Fraction, Number, StatValue};
+use bevy_reflect::Reflect;
+use serde::{Deserialize, Serialize};
+use std::ops::Neg;
+
+/// A [`StatValue`] that evaluates to the negation of `S` given the same operations,
+/// e.g. a resistance that reduces an incoming percentage.
+///
+/// All operations are applied to `S` as is, including bases and bounds,
+/// only the output of [`StatValue::eval`] is negated. This keeps rounding
+/// and aggregation of `S` intact, e.g. `Inverted<StatIntRounded<i32, f32, Floor>>`
+/// evaluates to `-floor(x)`, not `ceil(-x)`.
+///
+/// Only usable on types whose [`StatValue::Out`] is a signed [`Number`].
+/// Negating [`Number::LOWEST`] saturates to [`Number::HIGHEST`], e.g. for `i32::MIN`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[serde(transparent)]
+#[repr(transparent)]
+pub struct Inverted<S>(pub S);
+
+impl<S: StatValue> StatValue for Inverted<S>
+where
+    S::Out: Number + Neg<Output = S::Out>,
+{
+    type Out = S::Out;
+    type Base = S::Base;
+
+    fn join(&mut self, other: Self) {
+        self.0.join(other.0)
+    }
+
+    fn eval(&self) -> Self::Out {
+        negate(self.0.eval())
+    }
+
+    fn finish(self) -> Self::Out {
+        negate(self.0.finish())
+    }
+
+    type Add = S::Add;
+    type Mul = S::Mul;
+    type Bit = S::Bit;
+    type Bounds = S::Bounds;
+
+    fn add(&mut self, other: Self::Add) {
+        self.0.add(other)
+    }
+
+    fn mul(&mut self, other: Self::Mul) {
+        self.0.mul(other)
+    }
+
+    fn scale(&mut self, factor: Self::Mul) {
+        self.0.scale(factor)
+    }
+
+    fn or(&mut self, other: Self::Bit) {
+        self.0.or(other)
+    }
+
+    fn min(&mut self, other: Self::Bounds) {
+        self.0.min(other)
+    }
+
+    fn max(&mut self, other: Self::Bounds) {
+        self.0.max(other)
+    }
+
+    fn is_clamped(&self) -> bool {
+        self.0.is_clamped()
+    }
+
+    fn rebase(&mut self, old: &Self, new: &Self) {
+        self.0.rebase(&old.0, &new.0)
+    }
+
+    fn weighted_join(&mut self, other: Self, weight: Fraction<i32>) {
+        self.0.weighted_join(other.0, weight)
+    }
+
+    fn canonicalize(&mut self) {
+        self.0.canonicalize()
+    }
+
+    fn is_canonical(&self) -> bool {
+        self.0.is_canonical()
+    }
+
+    fn from_base(base: Self::Base) -> Self {
+        Self(S::from_base(base))
+    }
+
+    fn into_base(self) -> Option<Self::Base> {
+        self.0.into_base()
+    }
+}
+
+/// Negate `value`, saturating on [`Number::LOWEST`].
+fn negate<T: Number + Neg<Output = T>>(value: T) -> T {
+    if value == T::LOWEST {
+        T::HIGHEST
+    } else {
+        -value
+    }
+}
@@ -1,10 +1,45 @@
+use std::ops::{AddAssign, Sub};
+
+mod extremum;
 mod flags;
 mod float;
+#[cfg(feature = "glam")]
+mod glam_vec;
 mod int_pct;
 mod int_ratio;
+mod inverted;
 mod prioritized;
-pub use flags::StatFlags;
-pub use float::{StatFloat, StatFloatAdditive, StatMult};
-pub use int_pct::{StatIntPercent, StatIntPercentAdditive};
+mod stacking;
+/// Shared [`StatValue::is_clamped`](crate::StatValue::is_clamped) of types with `min` and `max` bounds,
+/// on the value before clamping.
+fn is_clamped<T: PartialOrd>(value: T, min: T, max: T) -> bool {
+    value >= max || value <= min
+}
+
+/// Shared [`StatValue::canonicalize`](crate::StatValue::canonicalize) of types with `min` and `max` bounds.
+///
+/// If `min > max` the value always evaluates to `min`, so `max` is redundant and set to `min`.
+fn canonical_bounds<T: PartialOrd + Copy>(min: T, max: &mut T) {
+    if min > *max {
+        *max = min;
+    }
+}
+
+/// Shared [`StatValue::rebase`](crate::StatValue::rebase) of types with an additive part.
+fn rebase_addend<T: Copy + AddAssign + Sub<Output = T>>(addend: &mut T, old: T, new: T) {
+    *addend += new - old;
+}
+
+pub use extremum::{StatExtremum, StatMaxAgg, StatMinAgg};
+pub use flags::{StatAll, StatFlags, StatFlagsOverride, StatSet};
+pub use float::{
+    StatComposite, StatFloat, StatFloatAdditive, StatFloatGuarded, StatFloatRounded, StatLayered,
+    StatMult, StatVector, StatWeightedMean,
+};
+#[cfg(feature = "glam")]
+pub use glam_vec::{StatVec2, StatVec3};
+pub use int_pct::{StatIntPercent, StatIntPercentAdditive, StatPercentSaturating};
 pub use int_ratio::{StatInt, StatIntRounded};
+pub use inverted::Inverted;
 pub use prioritized::Prioritized;
+pub use stacking::StatStacking;
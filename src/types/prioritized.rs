@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use bevy_reflect::TypePath;
+use bevy_reflect::Reflect;
 use serde::{Deserialize, Serialize};
 
 use crate::{operations::Unsupported, Shareable, StatValue};
@@ -10,7 +10,14 @@ use crate::{operations::Unsupported, Shareable, StatValue};
 ///
 /// The [`Default`] priority is `i32::MIN`, if created via `From` or `from_base`,
 /// priority is 0.
-#[derive(Debug, Clone, Copy, TypePath, Serialize, Deserialize)]
+///
+/// # Ties
+///
+/// When joining values of equal priority, the value joined last wins if `LAST`,
+/// otherwise the value joined first is kept. In a [`StatMap`](crate::StatMap),
+/// entries of the same stat are joined in order of their qualifiers,
+/// so the winner does not depend on insertion order.
+#[derive(Debug, Clone, Copy, Reflect, Serialize, Deserialize)]
 #[repr(C)]
 pub struct Prioritized<T, const LAST: bool = true> {
     value: T,
@@ -35,6 +42,15 @@ impl<T, const L: bool> Prioritized<T, L> {
         &self.value
     }
 
+    pub const fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
     pub fn into_inner(self) -> T {
         self.value
     }
@@ -53,11 +69,11 @@ impl<T: Shareable + Default, const R: bool> StatValue for Prioritized<T, R> {
     fn join(&mut self, other: Self) {
         if R {
             if self.priority <= other.priority {
-                self.value = other.value
+                *self = other
             }
         } else {
             if self.priority < other.priority {
-                self.value = other.value
+                *self = other
             }
         }
     }
@@ -82,4 +98,8 @@ impl<T: Shareable + Default, const R: bool> StatValue for Prioritized<T, R> {
             priority: 0,
         }
     }
+
+    fn into_base(self) -> Option<Self::Base> {
+        Some(self.value)
+    }
 }
@@ -0,0 +1,99 @@
+use crate::{operations::Unsupported, EvalValue, Int, StatValue};
+use bevy_reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+/// A stacking buff that counts up to `MAX` stacks of a per-stack amount.
+///
+/// Each [`StatValue::add`] or [`StatValue::from_base`] contributes one stack of its amount,
+/// joining sums both the stacks and the amounts.
+///
+/// Evaluates to the sum of all amounts if there are at most `MAX` stacks,
+/// otherwise to `amount * MAX / stacks`, i.e. `MAX` stacks of the average per-stack amount,
+/// rounded towards zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[repr(C, align(8))]
+pub struct StatStacking<T: Int, const MAX: u32> {
+    amount: T,
+    stacks: u32,
+}
+
+impl<T: Int, const MAX: u32> Default for StatStacking<T, MAX> {
+    fn default() -> Self {
+        Self {
+            amount: T::ZERO,
+            stacks: 0,
+        }
+    }
+}
+
+impl<T: Int, const MAX: u32> StatStacking<T, MAX> {
+    /// Create a value with a number of stacks of `amount`.
+    pub fn new(amount: T, stacks: u32) -> Self {
+        Self {
+            amount: amount * T::from_i64(stacks as i64),
+            stacks,
+        }
+    }
+
+    /// Returns the number of accumulated stacks, which may exceed `MAX`.
+    pub const fn stacks(&self) -> u32 {
+        self.stacks
+    }
+
+    /// Returns the number of stacks that take effect.
+    pub fn effective_stacks(&self) -> u32 {
+        Ord::min(self.stacks, MAX)
+    }
+
+    /// Returns the sum of all per-stack amounts, including stacks exceeding `MAX`.
+    pub const fn amount(&self) -> T {
+        self.amount
+    }
+}
+
+impl<T: Int, const MAX: u32> StatValue for StatStacking<T, MAX> {
+    type Out = T;
+    type Base = T;
+
+    fn join(&mut self, other: Self) {
+        self.amount += other.amount;
+        self.stacks = self.stacks.saturating_add(other.stacks);
+    }
+
+    fn eval(&self) -> Self::Out {
+        if self.stacks <= MAX {
+            return self.amount;
+        }
+        let clamped = self.amount.to_i64() as i128 * MAX as i128 / self.stacks as i128;
+        T::from_i64(clamped.clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+    }
+
+    type Add = T;
+    type Mul = Unsupported;
+    type Bounds = Unsupported;
+
+    type Bit = Unsupported;
+
+    fn add(&mut self, other: Self::Add) {
+        self.join(Self::from_base(other))
+    }
+
+    fn is_clamped(&self) -> bool {
+        self.stacks >= MAX
+    }
+
+    fn visit_eval(&self, f: &mut dyn FnMut(EvalValue)) {
+        f(EvalValue::Int(self.eval().to_i64()))
+    }
+
+    fn from_base(base: Self::Base) -> Self {
+        Self {
+            amount: base,
+            stacks: 1,
+        }
+    }
+
+    fn into_base(self) -> Option<Self::Base> {
+        Some(self.amount)
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{Qualifier, QualifierFlag, StatInst};
+use std::fmt::Debug;
+
+/// A type erased evaluated value passed to [`StatVisitor`] by [`StatValue::visit_eval`](crate::StatValue::visit_eval).
+#[derive(Debug, Clone, Copy)]
+pub enum EvalValue<'t> {
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    Other(&'t dyn Debug),
+}
+
+/// Visits entries of a [`StatMap`](crate::StatMap) with typed callbacks,
+/// without downcasting to concrete [`Stat::Value`](crate::Stat::Value)s.
+///
+/// By default all typed callbacks forward to [`StatVisitor::visit_other`].
+#[allow(unused_variables)]
+pub trait StatVisitor<Q: QualifierFlag> {
+    fn visit_int(&mut self, qualifier: &Qualifier<Q>, stat: StatInst, value: i64) {
+        self.visit_other(qualifier, stat, &value)
+    }
+
+    fn visit_float(&mut self, qualifier: &Qualifier<Q>, stat: StatInst, value: f64) {
+        self.visit_other(qualifier, stat, &value)
+    }
+
+    fn visit_bool(&mut self, qualifier: &Qualifier<Q>, stat: StatInst, value: bool) {
+        self.visit_other(qualifier, stat, &value)
+    }
+
+    fn visit_other(&mut self, qualifier: &Qualifier<Q>, stat: StatInst, value: &dyn Debug) {}
+}
+
+impl<Q: QualifierFlag> dyn StatVisitor<Q> + '_ {
+    pub(crate) fn visit(&mut self, qualifier: &Qualifier<Q>, stat: StatInst, value: EvalValue) {
+        match value {
+            EvalValue::Int(value) => self.visit_int(qualifier, stat, value),
+            EvalValue::Float(value) => self.visit_float(qualifier, stat, value),
+            EvalValue::Bool(value) => self.visit_bool(qualifier, stat, value),
+            EvalValue::Other(value) => self.visit_other(qualifier, stat, value),
+        }
+    }
+}
@@ -1,6 +1,10 @@
-use bevy_stat_query::types::StatIntRounded;
-use bevy_stat_query::Attribute;
+use bevy_ecs::world::World;
+use bevy_stat_query::types::{StatFloat, StatInt, StatIntRounded};
 use bevy_stat_query::Stat;
+use bevy_stat_query::StatValuePair;
+use bevy_stat_query::{attribute_tag, Attribute};
+use bevy_stat_query::{stat_instance, Int, StatExtension, StatValue};
+use std::marker::PhantomData;
 
 #[derive(Debug, Clone, Copy, Stat, PartialEq, Eq)]
 #[stat(value = "StatIntRounded<i32, f32>")]
@@ -24,6 +28,22 @@ pub enum NumStats {
 #[stat(value = "StatIntRounded<i32, f32>")]
 pub struct X;
 
+#[derive(Debug, Clone, Copy, Stat)]
+#[stat(value = "StatInt<T>")]
+pub struct Generic<T: Int>(PhantomData<T>);
+
+stat_instance!(Generic<i16> = "Generic<i16>");
+stat_instance!(Generic<i32> = "Generic<i32>");
+
+#[derive(Debug, Clone, Copy, Stat)]
+#[stat(value = "StatFloat<f32>")]
+pub struct Named<T: Int> {
+    p: PhantomData<T>,
+}
+
+stat_instance!(Named<u8> = "Named<u8>");
+stat_instance!(Named<u16> = "Named<u16>");
+
 use NumStats::*;
 use Stats::*;
 
@@ -70,3 +90,64 @@ pub fn test_derive() {
     assert_eq!(X::values().into_iter().count(), 1);
     assert_eq!(X.name(), "X");
 }
+
+#[test]
+pub fn test_cast_identity() {
+    for stat in NumStats::values() {
+        let mut pair = StatValuePair::new_default(&stat);
+        let (cast, _) = pair.cast_checked::<NumStats>().unwrap();
+        assert_eq!(cast, stat);
+        assert!(pair.cast_checked::<Stats>().is_none());
+    }
+    let mut pair = StatValuePair::new_default(&C);
+    assert_eq!(pair.cast::<Stats>().unwrap().0, C);
+    assert_eq!(pair.cast_checked::<Stats>().unwrap().0, C);
+}
+
+#[test]
+pub fn test_attribute_serde() {
+    let dragon = Attribute::from(CreatureType::Dragon);
+    let Attribute::Enum { tag, index } = dragon else {
+        panic!()
+    };
+    assert_eq!(tag, attribute_tag("derive::CreatureType"));
+    assert_eq!(index, 1);
+    assert_ne!(
+        Attribute::from(IsDragon),
+        Attribute::from(CreatureType::Beast)
+    );
+
+    let json = serde_json::to_string(&dragon).unwrap();
+    assert_eq!(serde_json::from_str::<Attribute>(&json).unwrap(), dragon);
+    let json = serde_json::to_string(&Attribute::from("dragon")).unwrap();
+    assert_eq!(
+        serde_json::from_str::<Attribute>(&json).unwrap(),
+        Attribute::String("dragon")
+    );
+}
+
+#[test]
+pub fn test_generic_derive() {
+    let i16_stat = Generic::<i16>(PhantomData);
+    let i32_stat = Generic::<i32>(PhantomData);
+    assert_ne!(i16_stat.to_inst(), i32_stat.to_inst());
+    assert_eq!(i16_stat.to_inst(), Generic::<i16>::from_index(0).to_inst());
+    assert!(i16_stat.to_inst().is_type::<Generic<i16>>());
+    assert!(!i16_stat.to_inst().is_type::<Generic<i32>>());
+    assert_eq!(i16_stat.name(), "Generic<i16>");
+    assert_eq!(i32_stat.to_inst().name(), "Generic<i32>");
+    assert_eq!(Named::<u8>::from_index(0).name(), "Named<u8>");
+    assert_eq!(Named::<u16>::from_index(0).name(), "Named<u16>");
+    assert!(std::ptr::eq(
+        <Generic<i16> as Stat>::vtable(),
+        <Generic<i16> as Stat>::vtable()
+    ));
+
+    // Distinct names do not conflict on registration.
+    let mut world = World::new();
+    world.register_stat::<Generic<i16>>();
+    world.register_stat::<Generic<i32>>();
+    let mut pair = StatValuePair::new(&i32_stat, StatInt::from_base(3));
+    assert!(pair.cast::<Generic<i16>>().is_none());
+    assert_eq!(pair.cast::<Generic<i32>>().unwrap().1.eval(), 3);
+}
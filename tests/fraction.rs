@@ -0,0 +1,181 @@
+use bevy_stat_query::{types::StatFloat, Float, Fraction, StatValue};
+use serde::{Deserialize, Serialize};
+
+#[test]
+pub fn fraction_to_float() {
+    assert_eq!(Fraction::new(1, 3).to_f64(), 1.0 / 3.0);
+    assert_eq!(Fraction::new(1, 3).to_f32(), 1.0 / 3.0);
+    assert_eq!(Fraction::new(-5, 2).to_f64(), -2.5);
+    assert_eq!(Fraction::new(5, -2).to_f32(), -2.5);
+    assert_eq!(Fraction::new(0u8, 7).to_f64(), 0.0);
+}
+
+#[test]
+pub fn fraction_recip_neg() {
+    assert_eq!(Fraction::new(3, 4).recip(), Fraction::new(4, 3));
+    assert_eq!(Fraction::new(-3, 4).recip(), Fraction::new(-4, 3));
+    assert_eq!(Fraction::new(2u32, 1).recip(), Fraction::new(1, 2));
+    assert_eq!(-Fraction::new(3, 4), Fraction::new(-3, 4));
+    assert_eq!(-Fraction::new(-3, 4), Fraction::new(3, 4));
+    assert_eq!(-Fraction::new(0, 4), Fraction::new(0, 1));
+}
+
+#[test]
+#[should_panic]
+pub fn fraction_recip_zero() {
+    let _ = Fraction::new(0, 4).recip();
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    #[serde(with = "bevy_stat_query::fraction::readable")]
+    readable: Fraction<i32>,
+    binary: Fraction<i32>,
+}
+
+#[test]
+pub fn fraction_string() {
+    assert_eq!(Fraction::new(3, 4).to_string(), "3/4");
+    assert_eq!(Fraction::new(6, -8).to_string(), "-3/4");
+    assert_eq!(Fraction::new(4, 2).to_string(), "2");
+    assert_eq!("3/4".parse::<Fraction<i32>>(), Ok(Fraction::new(3, 4)));
+    assert_eq!("6/8".parse::<Fraction<i32>>(), Ok(Fraction::new(3, 4)));
+    assert_eq!("5".parse::<Fraction<u8>>(), Ok(Fraction::new(5, 1)));
+    assert!("3/0".parse::<Fraction<i32>>().is_err());
+    assert!("a/b".parse::<Fraction<i32>>().is_err());
+}
+
+#[test]
+pub fn fraction_serde() {
+    let config = Config {
+        readable: Fraction::new(-3, 4),
+        binary: Fraction::new(5, 2),
+    };
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(json, r#"{"readable":"-3/4","binary":[5,2]}"#);
+    assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+    assert!(serde_json::from_str::<Config>(r#"{"readable":"x","binary":[5,2]}"#).is_err());
+
+    let bytes = postcard::to_allocvec(&config.binary).unwrap();
+    assert_eq!(
+        postcard::from_bytes::<Fraction<i32>>(&bytes).unwrap(),
+        config.binary
+    );
+}
+
+#[test]
+pub fn fraction_abs_clamp() {
+    let abs = Fraction::new(-3, 4).abs();
+    assert_eq!(abs, Fraction::new(3, 4));
+    assert_eq!((*abs.numer(), *abs.denom()), (3, 4));
+    let abs = Fraction::new(3, -4).abs();
+    assert_eq!(abs, Fraction::new(3, 4));
+    assert_eq!((*abs.numer(), *abs.denom()), (3, 4));
+    assert_eq!(Fraction::new(0, -4).abs(), Fraction::new(0, 1));
+
+    let (lo, hi) = (Fraction::new(1, 4), Fraction::new(3, 2));
+    assert_eq!(Fraction::new(1, 8).clamp(lo, hi), lo);
+    assert_eq!(Fraction::new(-5, 2).clamp(lo, hi), lo);
+    assert_eq!(Fraction::new(2, 3).clamp(lo, hi), Fraction::new(2, 3));
+    assert_eq!(Fraction::new(7, 4).clamp(lo, hi), hi);
+    assert_eq!(Fraction::new(3, 2).clamp(lo, hi), hi);
+}
+
+#[test]
+#[should_panic]
+pub fn fraction_clamp_inverted() {
+    let _ = Fraction::new(1, 2).clamp(Fraction::new(1, 1), Fraction::new(0, 1));
+}
+
+/// A downstream fixed point [`Float`] with three decimal places,
+/// implementing only the required items.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Milli(i64);
+
+impl std::ops::Add for Milli {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Milli(self.0 + rhs.0)
+    }
+}
+
+impl std::ops::Sub for Milli {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Milli(self.0 - rhs.0)
+    }
+}
+
+impl std::ops::Mul for Milli {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Milli(self.0 * rhs.0 / 1000)
+    }
+}
+
+impl std::ops::Div for Milli {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        Milli(self.0 * 1000 / rhs.0)
+    }
+}
+
+impl std::ops::AddAssign for Milli {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl std::ops::MulAssign for Milli {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl Float for Milli {
+    const ZERO: Self = Milli(0);
+    const ONE: Self = Milli(1000);
+    const MIN_VALUE: Self = Milli(i64::MIN);
+    const MAX_VALUE: Self = Milli(i64::MAX);
+
+    fn min(self, other: Self) -> Self {
+        Milli(Ord::min(self.0, other.0))
+    }
+
+    fn max(self, other: Self) -> Self {
+        Milli(Ord::max(self.0, other.0))
+    }
+
+    fn floor(self) -> Self {
+        Milli(self.0.div_euclid(1000) * 1000)
+    }
+
+    fn ceil(self) -> Self {
+        Milli(-(-self.0).div_euclid(1000) * 1000)
+    }
+
+    fn trunc(self) -> Self {
+        Milli(self.0 / 1000 * 1000)
+    }
+
+    fn round(self) -> Self {
+        (self + Milli(500)).floor()
+    }
+}
+
+#[test]
+pub fn float_default_methods() {
+    assert_eq!(Milli::from_i64(-13), Milli(-13000));
+    assert_eq!(Milli::from_i64(0), Milli(0));
+    assert_eq!(Milli(2500).to_f64(), 2.5);
+    assert_eq!(Milli(-1250).to_f64(), -1.25);
+    assert!((Milli(333).to_f64() - 0.333).abs() < 1e-12);
+    assert_eq!(Milli::MAX_VALUE.to_f64(), i64::MAX as f64 / 1000.0);
+    assert_eq!(Milli::from_ratio(7, 2), Milli(3500));
+    assert_eq!(Milli::from_ratio(-7, 3), Milli(-2333));
+    assert_eq!(Milli::from_ratio(1, -4), Milli(-250));
+
+    let mut value = StatFloat::from_base(Milli(1000));
+    value.weighted_join(StatFloat::from_base(Milli(2000)), Fraction::new(1, 4));
+    assert_eq!(value.eval(), Milli(1250));
+}
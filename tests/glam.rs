@@ -0,0 +1,46 @@
+#![cfg(feature = "glam")]
+use bevy_stat_query::{
+    types::{StatVec2, StatVec3},
+    Qualifier, QualifierQuery, Stat, StatMap, StatValue,
+};
+use glam::{Vec2, Vec3};
+
+#[derive(Debug, Clone, Copy, Stat)]
+#[stat(value = "StatVec2")]
+pub struct Knockback;
+
+#[test]
+pub fn vector_stats() {
+    let mut map = StatMap::<u32>::new();
+    map.insert_base(Qualifier::none(), Knockback, Vec2::new(1.0, 0.0));
+    map.insert_base(Qualifier::all_of(1), Knockback, Vec2::new(0.0, 2.0));
+    map.insert(
+        Qualifier::all_of(2),
+        Knockback,
+        StatVec2::default()
+            .with_add(Vec2::new(-3.0, 1.0))
+            .with_mul(2.0),
+    );
+    assert_eq!(
+        map.eval_stat(&QualifierQuery::none(), &Knockback),
+        Vec2::new(1.0, 0.0)
+    );
+    assert_eq!(
+        map.eval_stat(&QualifierQuery::Aggregate(1), &Knockback),
+        Vec2::new(1.0, 2.0)
+    );
+    assert_eq!(
+        map.eval_stat(&QualifierQuery::Aggregate(3), &Knockback),
+        Vec2::new(-4.0, 6.0)
+    );
+
+    let direction = StatVec3::from_base(Vec3::X)
+        .with_join(StatVec3::from_base(Vec3::Y))
+        .with_add(Vec3::Z)
+        .with_mul(0.5);
+    assert_eq!(direction.eval(), Vec3::splat(0.5));
+
+    let json = serde_json::to_string(&direction).unwrap();
+    let value: StatVec3 = serde_json::from_str(&json).unwrap();
+    assert_eq!(value, direction);
+}
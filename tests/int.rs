@@ -0,0 +1,152 @@
+use bevy_stat_query::Int;
+use std::num::{Saturating, Wrapping};
+
+macro_rules! test_bounds {
+    ($($ty: ty),* $(,)?) => {
+        $({
+            let lo = i64::try_from(<$ty>::MIN).unwrap_or(i64::MIN);
+            let hi = i64::try_from(<$ty>::MAX).unwrap_or(i64::MAX);
+            assert_eq!(<$ty as Int>::try_from_i64(lo), Some(lo as $ty));
+            assert_eq!(<$ty as Int>::try_from_i64(hi), Some(hi as $ty));
+            assert_eq!(<$ty as Int>::from_i64(lo), lo as $ty);
+            assert_eq!(<$ty as Int>::from_i64(hi), hi as $ty);
+            if lo > i64::MIN {
+                assert_eq!(<$ty as Int>::try_from_i64(lo - 1), None);
+                assert_eq!(<$ty as Int>::from_i64(lo - 1), <$ty>::MIN);
+                assert_eq!(<$ty as Int>::from_i64(i64::MIN), <$ty>::MIN);
+            }
+            if hi < i64::MAX {
+                assert_eq!(<$ty as Int>::try_from_i64(hi + 1), None);
+                assert_eq!(<$ty as Int>::from_i64(hi + 1), <$ty>::MAX);
+                assert_eq!(<$ty as Int>::from_i64(i64::MAX), <$ty>::MAX);
+            }
+        })*
+    };
+}
+
+#[test]
+pub fn int_from_i64_bounds() {
+    test_bounds!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+}
+
+#[test]
+pub fn int_newtype_from_i64_bounds() {
+    assert_eq!(Wrapping::<u8>::try_from_i64(255), Some(Wrapping(255)));
+    assert_eq!(Wrapping::<u8>::try_from_i64(256), None);
+    assert_eq!(Wrapping::<u8>::from_i64(256), Wrapping(255));
+    assert_eq!(
+        Wrapping::<i16>::try_from_i64(-32768),
+        Some(Wrapping(-32768))
+    );
+    assert_eq!(Wrapping::<i16>::try_from_i64(-32769), None);
+    assert_eq!(Wrapping::<i16>::from_i64(-32769), Wrapping(-32768));
+    assert_eq!(Saturating::<u32>::try_from_i64(-1), None);
+    assert_eq!(Saturating::<u32>::from_i64(-1), Saturating(0));
+    assert_eq!(
+        Saturating::<i64>::try_from_i64(i64::MIN),
+        Some(Saturating(i64::MIN))
+    );
+    assert_eq!(Saturating::<i64>::from_i64(i64::MAX), Saturating(i64::MAX));
+}
+
+#[test]
+pub fn int_gcd_lcm() {
+    assert_eq!(Int::gcd(12i32, 18), 6);
+    assert_eq!(Int::lcm(12i32, 18), 36);
+    assert_eq!(Int::gcd(7u8, 5), 1);
+    assert_eq!(Int::lcm(7u8, 5), 35);
+    assert_eq!(Int::gcd(-12i32, 18), 6);
+    assert_eq!(Int::lcm(-12i32, 18), 36);
+    assert_eq!(Int::gcd(-4i64, -6), 2);
+    assert_eq!(Int::lcm(-4i64, -6), 12);
+    assert_eq!(Int::gcd(0u32, 9), 9);
+    assert_eq!(Int::lcm(0u32, 9), 0);
+    assert_eq!(Int::gcd(0i16, 0), 0);
+    assert_eq!(Int::lcm(0i16, 0), 0);
+    // Dividing first avoids overflowing the intermediate product.
+    assert_eq!(Int::lcm(200u8, 100), 200);
+    assert_eq!(Int::gcd(Wrapping(10u16), Wrapping(4)), Wrapping(2));
+    assert_eq!(Int::lcm(Saturating(-10i16), Saturating(4)), Saturating(20));
+}
+
+/// A downstream [`Int`] implementing only the required items.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Level(i16);
+
+impl std::ops::Add for Level {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Level(self.0 + rhs.0)
+    }
+}
+
+impl std::ops::Sub for Level {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Level(self.0 - rhs.0)
+    }
+}
+
+impl std::ops::Mul for Level {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Level(self.0 * rhs.0)
+    }
+}
+
+impl std::ops::AddAssign for Level {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0
+    }
+}
+
+impl std::ops::MulAssign for Level {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.0 *= rhs.0
+    }
+}
+
+impl Int for Level {
+    const ZERO: Self = Level(0);
+    const ONE: Self = Level(1);
+    const MIN_VALUE: Self = Level(i16::MIN);
+    const MAX_VALUE: Self = Level(i16::MAX);
+
+    fn from_i64(value: i64) -> Self {
+        Level(value.clamp(i16::MIN as i64, i16::MAX as i64) as i16)
+    }
+
+    fn min(self, other: Self) -> Self {
+        Level(Ord::min(self.0, other.0))
+    }
+
+    fn max(self, other: Self) -> Self {
+        Level(Ord::max(self.0, other.0))
+    }
+
+    type PrimInt = i16;
+
+    fn into_fraction(self) -> bevy_stat_query::Fraction<i16> {
+        self.0.into_fraction()
+    }
+
+    fn build_fraction(self, denom: Self) -> bevy_stat_query::Fraction<i16> {
+        self.0.build_fraction(denom.0)
+    }
+
+    fn from_fraction(frac: bevy_stat_query::Fraction<i16>) -> Self {
+        Level(i16::from_fraction(frac))
+    }
+}
+
+#[test]
+pub fn int_default_methods() {
+    assert_eq!(Level(-12).to_i64(), -12);
+    assert_eq!(Level::MAX_VALUE.to_i64(), i16::MAX as i64);
+    assert_eq!(Level::MIN_VALUE.to_i64(), i16::MIN as i64);
+    assert_eq!(Level::try_from_i64(300), Some(Level(300)));
+    assert_eq!(Level::try_from_i64(40000), None);
+    assert_eq!(Level::try_from_i64(-40000), None);
+    assert_eq!(Level(-12).gcd(Level(18)), Level(6));
+    assert_eq!(Level(-12).lcm(Level(18)), Level(36));
+}
@@ -0,0 +1,31 @@
+use bevy_stat_query::types::StatIntRounded;
+use bevy_stat_query::{set_lenient_stat_index, Stat};
+
+#[derive(Debug, Clone, Copy, Stat, PartialEq, Eq)]
+#[stat(value = "StatIntRounded<i32, f32>")]
+pub enum Stats {
+    A,
+    B,
+    C,
+    D,
+}
+
+#[derive(Debug, Clone, Copy, Stat, PartialEq, Eq)]
+#[stat(value = "StatIntRounded<i32, f32>")]
+pub enum NumStats {
+    E = 2,
+    F = 0,
+    G,
+    H = 3,
+}
+
+// `set_lenient_stat_index` is global, so this test has its own binary.
+#[test]
+pub fn test_lenient_index() {
+    assert!(std::panic::catch_unwind(|| Stats::from_index(99)).is_err());
+    set_lenient_stat_index(true);
+    assert_eq!(Stats::from_index(99), Stats::A);
+    assert_eq!(Stats::from_index(2), Stats::B);
+    assert_eq!(NumStats::from_index(7), NumStats::E);
+    set_lenient_stat_index(false);
+}
@@ -0,0 +1,648 @@
+use bevy_stat_query::{
+    operations::{
+        StatOperation::{Add, Base, Max, Min, Mul},
+        Unsupported,
+    },
+    rounding::{Ceil, DynRounding, Floor, Round, Rounding, Truncate, TruncateSigned},
+    types::{
+        Inverted, Prioritized, StatComposite, StatFlags, StatFloat, StatFloatAdditive,
+        StatFloatGuarded, StatFloatRounded, StatInt, StatIntPercent, StatIntPercentAdditive,
+        StatIntRounded, StatLayered, StatMaxAgg, StatMinAgg, StatMult, StatPercentSaturating,
+        StatSet, StatStacking, StatVector, StatWeightedMean,
+    },
+    Fraction, StatValue,
+};
+
+#[test]
+pub fn eval_delta() {
+    let before = StatInt::<i32>::from_base(10).with_mul(2);
+    let after = before.with_add(5);
+    assert_eq!(StatValue::eval_delta(&before, &after), 10);
+    assert_eq!(StatValue::eval_delta(&after, &before), -10);
+}
+
+#[test]
+pub fn from_operations() {
+    let value = StatInt::<i32>::from_operations([Base(10), Mul(2), Max(15)]);
+    assert_eq!(value.eval(), 15);
+    let value = StatInt::<i32>::from_operations([Mul(2), Base(10)]);
+    assert_eq!(value.eval(), 10);
+    assert_eq!(StatInt::<i32>::from_operations([]).eval(), 0);
+}
+
+#[test]
+pub fn scale() {
+    let mut value = StatFloatAdditive::<f32>::from_base(10.0).with_mul(0.5);
+    assert_eq!(value.eval(), 15.0);
+    assert_eq!(value.with_mul(2.0).eval(), 35.0);
+    value.scale(2.0);
+    assert_eq!(value.eval(), 30.0);
+
+    // Percentage types scale in the same units as their multipliers.
+    let mut value = StatIntPercentAdditive::<i32>::from_base(10).with_mul(50);
+    value.scale(200);
+    assert_eq!(value.eval(), 30);
+
+    let mut value = StatIntPercent::<i32>::from_base(10).with_mul(150);
+    value.scale(200);
+    assert_eq!(value.eval(), 30);
+    value.scale(50);
+    assert_eq!(value.eval(), 15);
+
+    let mut value = StatInt::<i32>::from_base(10).with_add(5).with_max(40);
+    value.scale(2);
+    assert_eq!(value.eval(), 30);
+    value.scale(2);
+    assert_eq!(value.eval(), 40);
+
+    // Multipliers are not scaled, so joining only scales the contribution.
+    let mut value = StatInt::<i32>::from_base(10).with_mul(3);
+    value.scale(2);
+    assert_eq!(value.eval(), 60);
+    value.join(StatInt::from_base(1));
+    assert_eq!(value.eval(), 63);
+}
+
+#[test]
+pub fn simplify_ops() {
+    let ops = vec![Add(5), Add(3), Mul(2), Mul(3), Max(100), Max(40)];
+    let simplified = StatInt::<i32>::simplify_ops(ops.clone());
+    assert_eq!(simplified, vec![Add(8), Mul(6), Max(40)]);
+    assert_eq!(
+        StatInt::<i32>::from_operations(ops).eval(),
+        StatInt::<i32>::from_operations(simplified).eval()
+    );
+
+    let ops = vec![Add(5), Mul(50), Base(10), Mul(20), Add(2), Mul(30), Min(1)];
+    let simplified = StatIntPercentAdditive::<i32>::simplify_ops(ops.clone());
+    assert_eq!(simplified, vec![Base(10), Mul(50), Add(2), Min(1)]);
+    assert_eq!(
+        StatIntPercentAdditive::<i32>::from_operations(ops).eval(),
+        StatIntPercentAdditive::<i32>::from_operations(simplified).eval()
+    );
+}
+
+#[test]
+pub fn float_guarded() {
+    let mut value = StatFloatGuarded::<f32, 5>::from_base(10.0);
+    value.add(f32::NAN);
+    assert_eq!(value.eval(), 5.0);
+    value.add(1.0);
+    assert_eq!(value.eval(), 6.0);
+
+    let value = StatFloatGuarded::<f32>::default().with_mul(f32::INFINITY);
+    assert_eq!(value.eval(), 0.0);
+    let value = StatFloatGuarded::<f32>::from_base(f32::INFINITY).with_max(100.0);
+    assert_eq!(value.eval(), 100.0);
+    assert_eq!(value.with_mul(-1.0).eval(), -f32::MAX);
+
+    let value = StatFloat::<f32>::default().with_mul(f32::INFINITY);
+    assert_eq!(value.eval(), f32::MAX);
+
+    // Finite values are only clamped on eval, so the order of operations does not matter.
+    let ops = [Max(100.0), Add(150.0), Mul(0.5)];
+    let mut reversed = ops;
+    reversed.reverse();
+    assert_eq!(StatFloatGuarded::<f32>::from_operations(ops).eval(), 75.0);
+    assert_eq!(
+        StatFloatGuarded::<f32>::from_operations(reversed).eval(),
+        75.0
+    );
+    assert!(!StatFloatGuarded::<f32>::from_operations(ops).is_clamped());
+    let mut value = StatFloatGuarded::<f32>::from_base(150.0).with_max(100.0);
+    value.join(StatFloatGuarded::from_base(-100.0));
+    assert_eq!(value.eval(), 50.0);
+}
+
+#[test]
+pub fn vector() {
+    let mut value = StatVector::<f32, 3>::from_base([1.0, 2.0, 3.0]);
+    value.add([10.0, 0.0, -5.0]);
+    assert_eq!(value.eval(), [11.0, 2.0, -2.0]);
+    value.join(
+        StatVector::default()
+            .with_add([1.0, 1.0, 1.0])
+            .with_mul(2.0),
+    );
+    assert_eq!(value.eval(), [24.0, 6.0, -2.0]);
+    value.min(0.0);
+    value.max(10.0);
+    assert_eq!(value.eval(), [10.0, 6.0, 0.0]);
+    assert_eq!(value.upper_bound(), Some(10.0));
+
+    let ops = vec![
+        Add([1.0, 2.0, 3.0]),
+        Add([1.0, 1.0, 1.0]),
+        Mul(2.0),
+        Max(5.0),
+    ];
+    let simplified = StatVector::<f32, 3>::simplify_ops(ops.clone());
+    assert_eq!(simplified, vec![Add([2.0, 3.0, 4.0]), Mul(2.0), Max(5.0)]);
+    assert_eq!(
+        StatVector::<f32, 3>::from_operations(ops).eval(),
+        [4.0, 5.0, 5.0]
+    );
+
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(
+        serde_json::from_str::<StatVector<f32, 3>>(&json).unwrap(),
+        value
+    );
+    assert!(serde_json::from_str::<StatVector<f32, 4>>(&json).is_err());
+}
+
+#[test]
+pub fn join_many() {
+    let values = [
+        StatInt::<i32>::from_base(3).with_mul(2),
+        StatInt::from_base(4).with_min(20),
+        StatInt::from_base(5).with_max(30).with_mul(3),
+    ];
+    let mut joined = StatInt::<i32>::from_base(1);
+    joined.join_many(values.into_iter());
+    let mut expected = StatInt::<i32>::from_base(1);
+    for value in values {
+        expected.join(value);
+    }
+    assert_eq!(joined, expected);
+    assert_eq!(joined.eval(), 30);
+}
+
+#[test]
+pub fn dyn_rounding() {
+    let value = StatIntRounded::<i32, f32, DynRounding>::from_base(-5).with_mul(0.5);
+    assert_eq!(value.eval(), -2);
+    let ceil = value.with_rounding(DynRounding::Ceil);
+    let floor = value.with_rounding(DynRounding::Floor);
+    assert_eq!(ceil.eval(), -2);
+    assert_eq!(floor.eval(), -3);
+    assert_eq!(value.eval(), -2);
+    assert_eq!(floor.with_join(ceil).eval(), -3);
+    assert_eq!(ceil.with_join(floor).eval(), -2);
+
+    let json = serde_json::to_string(&floor).unwrap();
+    let de: StatIntRounded<i32, f32, DynRounding> = serde_json::from_str(&json).unwrap();
+    assert_eq!(de.eval(), -3);
+
+    assert_eq!(DynRounding::Ceil.round(-2.5f32), -2.0);
+    assert_eq!(DynRounding::TruncateSigned.round(0.5f32), 1.0);
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Label(Box<str>);
+
+impl StatValue for Label {
+    type Out = Box<str>;
+
+    fn join(&mut self, other: Self) {
+        if !other.0.is_empty() {
+            *self = other
+        }
+    }
+
+    fn eval(&self) -> Self::Out {
+        self.0.clone()
+    }
+
+    fn finish(self) -> Self::Out {
+        self.0
+    }
+
+    type Add = Unsupported;
+    type Mul = Unsupported;
+    type Bit = Unsupported;
+    type Bounds = Unsupported;
+    type Base = Box<str>;
+
+    fn from_base(base: Self::Base) -> Self {
+        Label(base)
+    }
+}
+
+#[test]
+pub fn finish() {
+    let label = Label::from_base("Excalibur".into());
+    let ptr = label.0.as_ptr();
+    let cloned = label.eval();
+    assert_ne!(cloned.as_ptr(), ptr);
+    let out = label.finish();
+    assert_eq!(&*out, "Excalibur");
+    assert_eq!(out.as_ptr(), ptr);
+
+    let value = StatInt::<i32>::from_base(4).with_mul(3).with_max(10);
+    assert_eq!(value.finish(), value.eval());
+}
+
+#[test]
+pub fn write_to_changed() {
+    let mut value = StatInt::<i32>::from_base(3);
+    assert!(!Add(0).write_to_changed(&mut value));
+    assert!(!Mul(1).write_to_changed(&mut value));
+    assert!(Add(5).write_to_changed(&mut value));
+    assert_eq!(value.eval(), 8);
+    assert!(Max(6).write_to_changed(&mut value));
+    assert!(!Max(10).write_to_changed(&mut value));
+    assert_eq!(value.eval(), 6);
+    assert!(!Base(8).write_to_changed(&mut StatInt::from_base(8)));
+    assert!(Base(8).write_to_changed(&mut value));
+}
+
+#[test]
+pub fn weighted_join() {
+    let mut accuracy = StatFloat::<f32>::from_base(0.5);
+    accuracy.weighted_join(StatFloat::from_base(0.9), Fraction::new(3, 10));
+    assert!((accuracy.eval() - 0.62).abs() < 1e-6);
+    accuracy.weighted_join(
+        StatFloat::from_base(0.62).with_max(0.6),
+        Fraction::new(1, 2),
+    );
+    assert_eq!(accuracy.eval(), 0.6);
+
+    // Order-dependent: a later source blends with the previous result.
+    let mut a = StatFloat::<f32>::from_base(10.0);
+    a.weighted_join(StatFloat::from_base(20.0), Fraction::new(1, 2));
+    a.weighted_join(StatFloat::from_base(40.0), Fraction::new(1, 2));
+    assert_eq!(a.eval(), 27.5);
+
+    let mut int = StatInt::<u32>::from_base(10);
+    int.weighted_join(StatInt::from_base(20).with_mul(2), Fraction::new(7, 10));
+    assert_eq!(int.eval(), 34);
+
+    let mut vector = StatVector::<f32, 2>::from_base([0.0, 10.0]);
+    vector.weighted_join(StatVector::from_base([10.0, 0.0]), Fraction::new(1, 4));
+    assert_eq!(vector.eval(), [2.5, 7.5]);
+}
+
+#[test]
+pub fn composite() {
+    type C = StatComposite<f32>;
+    let base = C::from_base(10.0);
+    assert_eq!(base.eval(), 10.0);
+
+    // Flat.
+    assert_eq!(base.with_add(6.0).eval(), 16.0);
+    assert_eq!(base.with_add(6.0).with_max(12.0).eval(), 12.0);
+    assert_eq!(base.with_min(20.0).eval(), 20.0);
+    // Additive percentage.
+    assert_eq!(base.with_percent(0.25).with_percent(0.25).eval(), 15.0);
+    // Multiplicative.
+    assert_eq!(base.with_mul(2.0).with_mul(1.5).eval(), 30.0);
+    // Cap.
+    assert_eq!(base.with_cap(8.0).eval(), 8.0);
+    assert_eq!(base.with_cap(8.0).upper_bound(), Some(8.0));
+    assert_eq!(base.upper_bound(), None);
+
+    // Bounds apply to the flat sum, percentages and multipliers can exceed them.
+    let value = base
+        .with_add(6.0)
+        .with_max(12.0)
+        .with_percent(0.5)
+        .with_mul(2.0);
+    assert_eq!(value.eval(), 36.0);
+    assert!(value.is_clamped());
+    // The cap cannot be exceeded by multipliers or by `min`.
+    assert_eq!(value.with_cap(30.0).eval(), 30.0);
+    assert_eq!(base.with_min(50.0).with_cap(30.0).eval(), 30.0);
+    assert!(!base.with_cap(30.0).is_clamped());
+
+    let joined = C::from_base(4.0)
+        .with_percent(0.5)
+        .with_cap(100.0)
+        .with_join(C::from_base(4.0).with_mul(3.0).with_percent(0.5))
+        .with_join(C::default().with_cap(40.0));
+    assert_eq!(joined.eval(), 40.0);
+    assert_eq!(joined.with_cap(50.0).upper_bound(), Some(40.0));
+    assert_eq!(
+        C::from_operations([Base(4.0), Add(4.0), Mul(3.0), Max(6.0)]).eval(),
+        18.0
+    );
+}
+
+#[test]
+pub fn into_base() {
+    assert_eq!(StatInt::<i32>::from_base(7).into_base(), Some(7));
+    assert_eq!(StatIntPercent::<i32>::from_base(-3).into_base(), Some(-3));
+    assert_eq!(
+        StatIntPercentAdditive::<i32>::from_base(9).into_base(),
+        Some(9)
+    );
+    assert_eq!(
+        StatIntRounded::<i32, f32>::from_base(12).into_base(),
+        Some(12)
+    );
+    assert_eq!(StatFloat::<f32>::from_base(1.5).into_base(), Some(1.5));
+    assert_eq!(
+        StatFloatAdditive::<f32>::from_base(2.5).into_base(),
+        Some(2.5)
+    );
+    assert_eq!(StatMult::<f32>::from_base(0.5).into_base(), Some(0.5));
+    assert_eq!(StatComposite::<f32>::from_base(4.0).into_base(), Some(4.0));
+    assert_eq!(
+        StatVector::<f32, 2>::from_base([1.0, 2.0]).into_base(),
+        Some([1.0, 2.0])
+    );
+    assert_eq!(StatFlags::<u32>::from_base(0b110).into_base(), Some(0b110));
+    assert_eq!(
+        Prioritized::<Box<str>>::from_base("Ferris".into()).into_base(),
+        Some("Ferris".into())
+    );
+    assert_eq!(true.into_base(), Some(true));
+}
+
+#[test]
+pub fn stacking() {
+    type Poison = StatStacking<i32, 5>;
+    let mut a = Poison::from_base(3).with_add(3);
+    assert_eq!(a.stacks(), 2);
+    assert_eq!(a.eval(), 6);
+
+    let b = Poison::new(3, 4);
+    a.join(b);
+    assert_eq!(a.stacks(), 6);
+    assert_eq!(a.effective_stacks(), 5);
+    assert!(a.is_clamped());
+    assert_eq!(a.eval(), 15);
+
+    let mut c = Poison::default();
+    assert_eq!(c.eval(), 0);
+    c.join(Poison::default());
+    c.join(Poison::from_base(-2));
+    assert_eq!(c.eval(), -2);
+    c.add(4);
+    assert_eq!(c.amount(), 2);
+    assert_eq!(c.eval(), 2);
+    assert!(!c.is_clamped());
+
+    // Partial stacks of different amounts sum, then clamp to `MAX` stacks of the average.
+    let weak = Poison::new(2, 3);
+    let strong = Poison::new(6, 3);
+    assert_eq!(weak.eval(), 6);
+    let mixed = weak.with_join(strong);
+    assert_eq!(mixed.amount(), 24);
+    assert_eq!(mixed.stacks(), 6);
+    assert_eq!(mixed.eval(), 20);
+    assert_eq!(mixed, strong.with_join(weak));
+}
+
+#[test]
+pub fn extremum() {
+    let auras = [1.5f32, 2.25, 0.5, -1.0];
+    let mut fastest = StatMaxAgg::<f32>::default();
+    let mut slowest = StatMinAgg::<f32>::default();
+    for aura in auras {
+        fastest.join(StatMaxAgg::from_base(aura));
+        slowest.join(StatMinAgg::from_base(aura));
+    }
+    assert_eq!(fastest.eval(), 2.25);
+    assert_eq!(slowest.eval(), -1.0);
+
+    fastest.add(1.0);
+    assert_eq!(fastest.eval(), 2.25);
+    fastest.add(3.0);
+    assert_eq!(fastest.eval(), 3.0);
+    fastest.max(2.5);
+    assert_eq!(fastest.eval(), 2.5);
+    assert!(fastest.is_clamped());
+    assert_eq!(fastest.upper_bound(), Some(2.5));
+
+    slowest.min(0.0);
+    assert_eq!(slowest.eval(), 0.0);
+
+    assert!(!StatMaxAgg::<f32>::default().is_clamped());
+    assert!(!StatMinAgg::<f32>::default().is_clamped());
+
+    let mut levels = StatMaxAgg::<i32>::default().with_min(0);
+    assert_eq!(levels.eval(), 0);
+    levels.join(StatMaxAgg::from_base(-5));
+    assert_eq!(levels.eval(), 0);
+    levels.join(StatMaxAgg::from_base(4).with_max(3));
+    assert_eq!(levels.eval(), 3);
+
+    let json = serde_json::to_string(&fastest).unwrap();
+    let value: StatMaxAgg<f32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(value, fastest);
+    let json = serde_json::to_string(&levels).unwrap();
+    let value: StatMaxAgg<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(value, levels);
+}
+
+#[test]
+pub fn weighted_mean() {
+    let empty = StatWeightedMean::<f32>::default();
+    assert_eq!(empty.eval(), 0.0);
+    assert_eq!(empty.with_add((5.0, 0.0)).eval(), 0.0);
+
+    let mut mean = StatWeightedMean::<f32>::from_base(2.0);
+    assert_eq!(mean.eval(), 2.0);
+    mean.add((5.0, 2.0));
+    assert_eq!(mean.eval(), 4.0);
+    mean.join(StatWeightedMean::default().with_add((0.0, 1.0)));
+    assert_eq!(mean.total_weight(), 4.0);
+    assert_eq!(mean.eval(), 3.0);
+
+    let fraction = StatWeightedMean::<Fraction<i32>>::default()
+        .with_add((Fraction::new(1, 1), Fraction::new(1, 1)))
+        .with_add((Fraction::new(2, 1), Fraction::new(2, 1)));
+    assert_eq!(fraction.eval(), Fraction::new(5, 3));
+
+    let json = serde_json::to_string(&mean).unwrap();
+    let value: StatWeightedMean<f32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(value, mean);
+    assert_eq!(value.eval(), 3.0);
+}
+
+#[test]
+pub fn set() {
+    let mut fire_ice = StatSet::<u8>::from_base(vec![2, 1, 2]);
+    assert_eq!(fire_ice.as_slice(), [1, 2]);
+    fire_ice.or(4);
+    fire_ice.or(1);
+    assert_eq!(fire_ice.eval(), [1, 2, 4]);
+
+    let ice_poison = StatSet::default().with_or(8).with_or(2);
+    let hit = fire_ice.clone().with_join(ice_poison);
+    assert_eq!(hit.eval(), [1, 2, 4, 8]);
+    assert!(hit.contains(&8));
+    assert!(!hit.contains(&16));
+    assert_eq!(hit.clone().finish(), [1, 2, 4, 8]);
+
+    let json = serde_json::to_string(&hit).unwrap();
+    assert_eq!(json, "[1,2,4,8]");
+    let value: StatSet<u8> = serde_json::from_str(&json).unwrap();
+    assert_eq!(value, hit);
+    let value: StatSet<u8> = serde_json::from_str("[4,1,4]").unwrap();
+    assert_eq!(value.as_slice(), [1, 4]);
+    assert!(value.contains(&4));
+}
+
+#[test]
+pub fn layered() {
+    // 100 base, +28 flat, 50% and 25% increased, 100% and 50% more.
+    let weapon = StatLayered::<f32>::from_base(100.0).with_increase(0.5);
+    let ring = StatLayered::default()
+        .with_add(28.0)
+        .with_increase(0.25)
+        .with_mul(2.0);
+    let support = StatLayered::default().with_mul(1.5);
+    let damage = weapon.with_join(ring).with_join(support);
+    assert_eq!(damage.eval(), (100.0 + 28.0) * 1.75 * 3.0);
+    assert_eq!(damage.eval(), 672.0);
+    assert!(!damage.is_clamped());
+
+    let capped = damage.with_max(500.0);
+    assert_eq!(capped.eval(), 500.0);
+    assert!(capped.is_clamped());
+    assert_eq!(capped.upper_bound(), Some(500.0));
+    assert_eq!(capped.into_base(), Some(100.0));
+
+    // Bounds apply to the final product, not `base + flat`.
+    let floored = StatLayered::<f32>::from_base(10.0)
+        .with_increase(-0.5)
+        .with_min(8.0);
+    assert_eq!(floored.eval(), 8.0);
+    assert!(floored.is_clamped());
+    let value = StatLayered::<f32>::from_base(10.0)
+        .with_max(15.0)
+        .with_mul(2.0);
+    assert_eq!(value.eval(), 15.0);
+    assert!(value.is_clamped());
+
+    let json = serde_json::to_string(&damage).unwrap();
+    let value: StatLayered<f32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(value, damage);
+    assert_eq!(value.eval(), 672.0);
+}
+
+#[test]
+pub fn percent_saturating() {
+    let value = StatPercentSaturating::<i32>::from_base(i32::MAX - 1).with_mul(i32::MAX - 1);
+    assert_eq!(value.eval(), i32::MAX);
+    assert!(value.with_max(100).is_clamped());
+    assert_eq!(value.with_max(100).eval(), 100);
+
+    let value = StatPercentSaturating::<i32>::from_base(i32::MIN + 1).with_mul(i32::MAX);
+    assert_eq!(value.eval(), i32::MIN);
+
+    let value = StatPercentSaturating::<i32>::from_base(i32::MAX)
+        .with_add(i32::MAX)
+        .with_join(StatPercentSaturating::from_base(i32::MAX));
+    assert_eq!(value.eval(), i32::MAX);
+
+    let value = StatPercentSaturating::<i32>::from_base(200)
+        .with_mul(50)
+        .with_mul(-25);
+    assert_eq!(value.eval(), 250);
+    assert_eq!(
+        value.eval(),
+        StatIntPercentAdditive::<i32>::from_base(200)
+            .with_mul(50)
+            .with_mul(-25)
+            .eval()
+    );
+
+    let value = StatPercentSaturating::<u8, 10>::from_base(200).with_mul(250);
+    assert_eq!(value.eval(), u8::MAX);
+}
+
+#[test]
+pub fn float_rounded() {
+    fn eval<R: Rounding>(value: f32) -> f32 {
+        StatFloatRounded::<f32, R>::from_base(value).eval()
+    }
+    assert_eq!(eval::<Round>(2.5), 3.0);
+    assert_eq!(eval::<Round>(-2.5), -3.0);
+    assert_eq!(eval::<Truncate>(2.5), 2.0);
+    assert_eq!(eval::<Truncate>(-2.5), -2.0);
+    assert_eq!(eval::<Floor>(2.5), 2.0);
+    assert_eq!(eval::<Floor>(-2.5), -3.0);
+    assert_eq!(eval::<Ceil>(2.5), 3.0);
+    assert_eq!(eval::<Ceil>(-2.5), -2.0);
+    assert_eq!(eval::<TruncateSigned>(2.5), 2.0);
+    assert_eq!(eval::<TruncateSigned>(-2.5), -2.0);
+    assert_eq!(eval::<TruncateSigned>(0.5), 1.0);
+    assert_eq!(eval::<TruncateSigned>(-0.5), -1.0);
+
+    let gold = StatFloatRounded::<f32>::from_base(10.0)
+        .with_mul(1.15)
+        .with_max(10.5);
+    assert_eq!(gold.eval(), 11.0);
+    assert!(gold.is_clamped());
+    assert_eq!(StatFloatRounded::<f32>::default().eval(), 0.0);
+}
+
+#[test]
+pub fn inverted() {
+    let sequences = [
+        vec![Base(10), Add(5), Mul(2)],
+        vec![Base(10), Add(5), Mul(2), Max(25)],
+        vec![Add(-7), Min(-3), Mul(3)],
+        vec![Base(4), Min(10), Max(20), Add(100)],
+    ];
+    for ops in sequences {
+        let value = StatInt::<i32>::from_operations(ops.clone());
+        let inverted =
+            Inverted::<StatInt<i32>>::from_operations(ops.into_iter().map(|op| match op {
+                Base(x) => Base(x),
+                Add(x) => Add(x),
+                Mul(x) => Mul(x),
+                Min(x) => Min(x),
+                Max(x) => Max(x),
+                _ => unreachable!(),
+            }));
+        assert_eq!(inverted.eval(), -value.eval());
+        assert_eq!(inverted.is_clamped(), value.is_clamped());
+    }
+
+    let resistance = Inverted::<StatIntPercentAdditive<i32>>::from_base(40)
+        .with_add(10)
+        .with_mul(50);
+    assert_eq!(resistance.eval(), -75);
+    assert_eq!(resistance.into_base(), Some(50));
+
+    let float = Inverted::<StatFloat<f32>>::from_base(1.5).with_join(Inverted::from_base(0.5));
+    assert_eq!(float.eval(), -2.0);
+
+    // Rounding applies to the inner value before negation.
+    let floor = Inverted::<StatIntRounded<i32, f32, Floor>>::from_base(5).with_mul(0.5);
+    assert_eq!(floor.eval(), -2);
+    let ceil = Inverted::<StatIntRounded<i32, f32, Ceil>>::from_base(5).with_mul(0.5);
+    assert_eq!(ceil.eval(), -3);
+
+    // Aggregation keeps the highest inner value.
+    let highest = Inverted::<StatMaxAgg<i32>>::from_base(3)
+        .with_add(7)
+        .with_add(5);
+    assert_eq!(highest.eval(), -7);
+    assert_eq!(highest.into_base(), Some(7));
+
+    // Negating the lowest value saturates.
+    assert_eq!(Inverted::<StatMaxAgg<i32>>::default().eval(), i32::MAX);
+    let lowest = Inverted::<StatMaxAgg<i32>>::from_base(i32::MIN).with_add(i32::MIN);
+    assert_eq!(lowest.into_base(), Some(i32::MIN));
+    assert_eq!(lowest.eval(), i32::MAX);
+}
+
+#[test]
+pub fn prioritized_ties() {
+    fn join_all<const LAST: bool>(values: [(u32, i32); 3]) -> Prioritized<u32, LAST> {
+        let mut result = Prioritized::default();
+        for (value, priority) in values {
+            result.join(Prioritized::new(value, priority));
+        }
+        result
+    }
+
+    let ties = [(1, 2), (2, 2), (3, 2)];
+    assert_eq!(join_all::<false>(ties).eval(), 1);
+    assert_eq!(join_all::<true>(ties).eval(), 3);
+    assert_eq!(join_all::<true>(ties).priority(), 2);
+
+    let mixed = [(1, 5), (2, 1), (3, 5)];
+    assert_eq!(join_all::<false>(mixed).eval(), 1);
+    assert_eq!(join_all::<true>(mixed).eval(), 3);
+    assert_eq!(join_all::<true>(mixed).priority(), 5);
+
+    let raised = Prioritized::<u32>::from(4).with_priority(3);
+    assert_eq!(raised.priority(), 3);
+    assert_eq!(raised.eval(), 4);
+}
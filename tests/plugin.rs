@@ -0,0 +1,78 @@
+use bevy_app::App;
+use bevy_ecs::entity::Entity;
+use bevy_stat_query::{
+    types::StatInt, GlobalStatDefaults, GlobalStatRelations, OwnedQuerier, Qualifier,
+    QualifierQuery, Querier, Stat, StatDeserializers, StatExtension, StatMap, StatValue,
+};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Debug, Clone, Copy, Stat)]
+#[stat(value = "StatInt<i32>")]
+pub struct Health;
+
+#[derive(Debug, Clone, Copy, Stat)]
+#[stat(value = "StatInt<i32>")]
+pub struct Mana;
+
+#[test]
+pub fn register_stat_for() {
+    let mut app = App::new();
+    app.register_stat_for::<Health, u32>();
+    let world = app.world();
+    assert!(world.contains_resource::<StatDeserializers>());
+    assert!(world.contains_resource::<GlobalStatDefaults>());
+    assert!(world.contains_resource::<GlobalStatRelations<u32>>());
+    assert!(!world.contains_resource::<GlobalStatRelations<u64>>());
+
+    app.register_stat_max(&Health, 100);
+    app.register_stat_for::<Mana, u32>()
+        .register_stat_for::<Health, u32>();
+    assert_eq!(
+        app.world()
+            .resource::<GlobalStatDefaults>()
+            .get(&Health)
+            .upper_bound(),
+        Some(100)
+    );
+}
+
+#[test]
+pub fn to_inst() {
+    let mut app = App::new();
+    app.register_stat::<Health>().register_stat::<Mana>();
+    let deserializers = app.world().resource::<StatDeserializers>();
+    let health = deserializers.get("Health").unwrap();
+    assert_eq!(Health.to_inst(), health);
+    assert_ne!(Mana.to_inst(), health);
+    assert!(health.is(&Health));
+    assert!(health.is_type::<Health>());
+    assert_eq!(Health.to_inst().name(), "Health");
+}
+
+static RELATION_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[test]
+pub fn register_stat_relation_for() {
+    let mut app = App::new();
+    app.register_stat_relation_for(Health, |entity, qualifier, value, querier: Querier<u32>| {
+        RELATION_CALLS.fetch_add(1, Ordering::Relaxed);
+        value.add(querier.eval_stat(entity, qualifier, &Mana).unwrap() * 2);
+    });
+    let relations = app.world().resource::<GlobalStatRelations<u32>>();
+
+    let mut map = StatMap::<u32>::new();
+    map.insert_base(Qualifier::none(), Health, 10);
+    map.insert_base(Qualifier::none(), Mana, 3);
+    let entity = Entity::PLACEHOLDER;
+    let querier = OwnedQuerier::new(&map).with_relations(relations);
+    assert_eq!(
+        querier.eval_stat(entity, &QualifierQuery::none(), &Mana),
+        Some(3)
+    );
+    assert_eq!(RELATION_CALLS.load(Ordering::Relaxed), 0);
+    assert_eq!(
+        querier.eval_stat(entity, &QualifierQuery::none(), &Health),
+        Some(16)
+    );
+    assert_eq!(RELATION_CALLS.load(Ordering::Relaxed), 1);
+}
@@ -1,7 +1,7 @@
 use bevy_stat_query::{
     operations::StatOperation::{Add, Max, Mul},
     types::StatIntPercentAdditive,
-    Qualifier, QualifierFlag, QualifierQuery, Stat, StatMap,
+    Qualifier, QualifierFlag, QualifierPair, QualifierQuery, Stat, StatMap,
 };
 
 bitflags::bitflags! {
@@ -17,6 +17,14 @@ bitflags::bitflags! {
     }
 }
 
+bitflags::bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+    struct Range: u32 {
+        const Melee = 1;
+        const Ranged = 2;
+    }
+}
+
 #[derive(Debug, Clone, Copy, Stat)]
 #[stat(value = "StatIntPercentAdditive<i32>")]
 pub struct S;
@@ -137,3 +145,53 @@ pub fn qualifier_test() {
         2
     );
 }
+
+#[test]
+pub fn qualifier_pair_test() {
+    type P = QualifierPair<Q, Range>;
+    let fire_melee = QualifierPair(Q::Fire, Range::Melee);
+    let fire = QualifierPair(Q::Fire, Range::empty());
+    let melee = QualifierPair(Q::empty(), Range::Melee);
+
+    assert!(P::none().is_none());
+    assert!(!fire.is_none());
+    assert_eq!(fire | melee, fire_melee);
+    assert!(fire_melee.contains(&fire));
+    assert!(fire_melee.contains(&melee));
+    assert!(!fire.contains(&fire_melee));
+    assert!(!fire.contains(&melee));
+    assert!(fire.intersects(&fire_melee));
+    assert!(melee.intersects(&fire_melee));
+    assert!(!fire.intersects(&melee));
+    assert!(!QualifierPair(Q::Water, Range::Ranged).intersects(&fire_melee));
+
+    let mut map = StatMap::<P>::new();
+    map.insert_base(Qualifier::none(), S, 1);
+    map.modify(Qualifier::all_of(fire), S, Add(2));
+    map.modify(Qualifier::all_of(fire_melee), S, Add(4));
+    assert_eq!(map.eval_stat(&QualifierQuery::Aggregate(melee), &S), 1);
+    assert_eq!(map.eval_stat(&QualifierQuery::Aggregate(fire), &S), 3);
+    assert_eq!(map.eval_stat(&QualifierQuery::Aggregate(fire_melee), &S), 7);
+}
+
+#[test]
+pub fn qualifier_any_never() {
+    let none = Qualifier::<Q>::none();
+    let fire_magic = Qualifier::all_of(Q::Fire | Q::Magic);
+    let elemental = Qualifier::any_of(Q::Fire | Q::Water | Q::Earth | Q::Air);
+
+    for qualifier in [none, fire_magic, elemental] {
+        assert!(qualifier.qualifies_as(&QualifierQuery::any()));
+        assert!(!qualifier.qualifies_as(&QualifierQuery::never()));
+    }
+    assert!(QualifierQuery::<Q>::any().qualifies_none());
+    assert!(!QualifierQuery::<Q>::never().qualifies_none());
+
+    let mut map = StatMap::<Q>::new();
+    map.insert_base(none, S, 1);
+    map.modify(fire_magic, S, Add(2));
+    map.modify(elemental, S, Add(4));
+    assert_eq!(map.eval_stat(&QualifierQuery::none(), &S), 1);
+    assert_eq!(map.eval_stat(&QualifierQuery::any(), &S), 7);
+    assert_eq!(map.eval_stat(&QualifierQuery::never(), &S), 0);
+}
@@ -0,0 +1,952 @@
+use bevy_ecs::{component::Component, entity::Entity, system::RunSystemOnce, world::World};
+use bevy_hierarchy::BuildChildren;
+use bevy_stat_query::{
+    match_stat,
+    operations::{
+        StatOperation::{Max, Mul},
+        Unsupported,
+    },
+    types::{StatAll, StatFlagsOverride, StatFloat, StatInt},
+    Attribute, DynStatStream, GlobalStatDefaults, GlobalStatRelations, OwnedQuerier, ParentQuery,
+    Qualifier, QualifierQuery, Querier, QueryStream, Stat, StatEntities, StatEntity, StatError,
+    StatExtension, StatInst, StatMap, StatQuery, StatStream, StatStreamExt, StatValue,
+    StatValuePair, WorldStream,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Debug, Clone, Copy, Stat)]
+#[stat(value = "StatInt<i32>")]
+pub enum Stats {
+    Strength,
+    Damage,
+}
+
+#[derive(Debug, Clone, Copy, Stat)]
+#[stat(value = "StatInt<i32>")]
+pub struct Other;
+
+#[derive(Debug, Clone, Copy, Stat)]
+#[stat(value = "StatInt<i32>")]
+pub enum Attributes {
+    Strength,
+    Agility,
+    Wisdom,
+}
+
+#[test]
+pub fn owned_querier() {
+    let mut defaults = GlobalStatDefaults::new();
+    defaults.insert(Stats::Damage, StatInt::default().with_max(20));
+    let relations =
+        GlobalStatRelations::<u32>::default().with(|entity, qualifier, stat, querier| {
+            match_stat!(stat => {
+                (Stats::Damage, value) => {
+                    value.add(querier.eval_stat(entity, qualifier, &Stats::Strength).unwrap() * 2);
+                }
+            })
+        });
+    let mut map = StatMap::<u32>::new();
+    map.insert_base(Qualifier::none(), Stats::Strength, 4);
+    map.insert_base(Qualifier::all_of(1), Stats::Strength, 8);
+    map.insert_base(Qualifier::none(), Stats::Damage, 1);
+
+    let entity = Entity::PLACEHOLDER;
+    let querier = OwnedQuerier::new(&map);
+    assert_eq!(
+        querier.eval_stat(entity, &QualifierQuery::none(), &Stats::Damage),
+        Some(1)
+    );
+
+    let querier = querier.with_defaults(&defaults).with_relations(&relations);
+    assert_eq!(
+        querier.eval_stat(entity, &QualifierQuery::none(), &Stats::Damage),
+        Some(9)
+    );
+    assert_eq!(
+        querier.eval_stat(entity, &QualifierQuery::Aggregate(1), &Stats::Damage),
+        Some(20)
+    );
+}
+
+#[test]
+pub fn eval_all() {
+    let relations =
+        GlobalStatRelations::<u32>::default().with(|entity, qualifier, stat, querier| {
+            match_stat!(stat => {
+                (Stats::Damage, value) => {
+                    let [strength, agility, wisdom] = querier.eval_all(
+                        entity,
+                        qualifier,
+                        [&Attributes::Strength, &Attributes::Agility, &Attributes::Wisdom],
+                    ).unwrap();
+                    value.add(strength + agility * wisdom);
+                }
+            })
+        });
+    let mut map = StatMap::<u32>::new();
+    map.insert_base(Qualifier::none(), Attributes::Strength, 4);
+    map.insert_base(Qualifier::none(), Attributes::Agility, 3);
+    map.insert_base(Qualifier::none(), Attributes::Wisdom, 5);
+    map.insert_base(Qualifier::all_of(1), Attributes::Wisdom, 2);
+
+    let entity = Entity::PLACEHOLDER;
+    let querier = OwnedQuerier::new(&map).with_relations(&relations);
+    let querier = querier.querier();
+    assert_eq!(
+        querier.eval_all(
+            entity,
+            &QualifierQuery::none(),
+            [
+                &Attributes::Strength,
+                &Attributes::Agility,
+                &Attributes::Wisdom
+            ]
+        ),
+        Some([4, 3, 5])
+    );
+    assert_eq!(
+        querier.eval_stat(entity, &QualifierQuery::none(), &Stats::Damage),
+        Some(19)
+    );
+    assert_eq!(
+        querier.eval_stat(entity, &QualifierQuery::Aggregate(1), &Stats::Damage),
+        Some(25)
+    );
+}
+
+#[test]
+pub fn eval_stat_max() {
+    let mut defaults = GlobalStatDefaults::new();
+    defaults.patch(&Stats::Damage, Max(100));
+    let mut map = StatMap::<u32>::new();
+    map.insert_base(Qualifier::none(), Stats::Damage, 120);
+    map.modify(Qualifier::all_of(1), Stats::Damage, Max(80));
+
+    let entity = Entity::PLACEHOLDER;
+    let querier = OwnedQuerier::new(&map).with_defaults(&defaults);
+    let querier = querier.querier();
+    assert_eq!(
+        querier.eval_stat(entity, &QualifierQuery::none(), &Stats::Damage),
+        Some(100)
+    );
+    assert_eq!(
+        querier.eval_stat_max(entity, &QualifierQuery::none(), &Stats::Damage),
+        Some(100)
+    );
+    assert_eq!(
+        querier.eval_stat_max(entity, &QualifierQuery::Aggregate(1), &Stats::Damage),
+        Some(80)
+    );
+    assert_eq!(
+        querier.eval_stat_max(entity, &QualifierQuery::none(), &Stats::Strength),
+        None
+    );
+}
+
+#[test]
+pub fn is_clamped() {
+    let mut defaults = GlobalStatDefaults::new();
+    defaults.patch(&Stats::Damage, Max(100));
+    let mut map = StatMap::<u32>::new();
+    map.insert_base(Qualifier::none(), Stats::Damage, 90);
+    map.insert_base(Qualifier::all_of(1), Stats::Damage, 30);
+
+    let entity = Entity::PLACEHOLDER;
+    let querier = OwnedQuerier::new(&map).with_defaults(&defaults);
+    let querier = querier.querier();
+    assert_eq!(
+        querier.is_stat_clamped(entity, &QualifierQuery::none(), &Stats::Damage),
+        Some(false)
+    );
+    assert_eq!(
+        querier.eval_stat(entity, &QualifierQuery::Aggregate(1), &Stats::Damage),
+        Some(100)
+    );
+    assert_eq!(
+        querier.is_stat_clamped(entity, &QualifierQuery::Aggregate(1), &Stats::Damage),
+        Some(true)
+    );
+    assert!(StatInt::<i32>::from_base(-5).with_min(0).is_clamped());
+    // Sitting exactly at a bound counts as clamped.
+    assert!(StatInt::<i32>::from_base(10).with_max(10).is_clamped());
+    assert!(StatInt::<i32>::from_base(0).with_min(0).is_clamped());
+    assert!(!StatInt::<i32>::from_base(9).with_max(10).is_clamped());
+    assert!(!StatInt::<i32>::default().is_clamped());
+}
+
+pub struct Silence;
+
+impl StatStream for Silence {
+    type Qualifier = u32;
+
+    fn stream_stat(
+        &self,
+        _: Entity,
+        _: &QualifierQuery<u32>,
+        stat_value: &mut StatValuePair,
+        _: Querier<u32>,
+    ) {
+        if stat_value.is(&Stats::Damage) {
+            stat_value.reset_to_default();
+        } else {
+            assert!(stat_value.reset_to::<Stats>(StatInt::from_base(1)));
+        }
+    }
+}
+
+#[test]
+pub fn reset_stream() {
+    let mut before = StatMap::<u32>::new();
+    before.insert_base(Qualifier::none(), Stats::Strength, 4);
+    before.insert_base(Qualifier::none(), Stats::Damage, 10);
+    let mut after = StatMap::<u32>::new();
+    after.insert_base(Qualifier::none(), Stats::Strength, 2);
+    after.insert_base(Qualifier::none(), Stats::Damage, 3);
+
+    let entity = Entity::PLACEHOLDER;
+    let querier = OwnedQuerier::new(&before).join(Silence).join(&after);
+    assert_eq!(
+        querier.eval_stat(entity, &QualifierQuery::none(), &Stats::Damage),
+        Some(3)
+    );
+    assert_eq!(
+        querier.eval_stat(entity, &QualifierQuery::none(), &Stats::Strength),
+        Some(3)
+    );
+
+    let mut pair = StatValuePair::new(&Stats::Damage, StatInt::from_base(5));
+    assert!(!pair.reset_to::<Other>(Default::default()));
+    assert_eq!(pair.into_result::<Stats>().unwrap().eval(), 5);
+}
+
+#[test]
+pub fn dyn_streams() {
+    let mut map = StatMap::<u32>::new();
+    map.insert_base(Qualifier::none(), Stats::Strength, 4);
+    map.insert_base(Qualifier::none(), Stats::Damage, 10);
+    let mut after = StatMap::<u32>::new();
+    after.insert_base(Qualifier::none(), Stats::Damage, 3);
+
+    let streams: Vec<Box<dyn DynStatStream<u32>>> =
+        vec![Box::new(map), Box::new(Silence), Box::new(after)];
+    let entity = Entity::PLACEHOLDER;
+    let querier = OwnedQuerier::new(&streams);
+    assert_eq!(
+        querier.eval_stat(entity, &QualifierQuery::none(), &Stats::Damage),
+        Some(3)
+    );
+    assert_eq!(
+        querier.eval_stat(entity, &QualifierQuery::none(), &Stats::Strength),
+        Some(1)
+    );
+}
+
+pub struct Burning(bool);
+
+impl StatStream for Burning {
+    type Qualifier = u32;
+
+    fn has_attribute(&self, _: Entity, attribute: Attribute) -> bool {
+        self.0 && attribute == Attribute::String("burning")
+    }
+}
+
+#[test]
+pub fn stream_combinators() {
+    let mut map = StatMap::<u32>::new();
+    map.insert(
+        Qualifier::none(),
+        Stats::Damage,
+        StatInt::from_base(10).with_mul(2),
+    );
+    map.insert_base(Qualifier::none(), Stats::Strength, 3);
+    let mut base = StatMap::<u32>::new();
+    base.insert_base(Qualifier::none(), Stats::Damage, 1);
+
+    let stream = (&map)
+        .qualified(Qualifier::all_of(1))
+        .when_attr("burning")
+        .scaled(Stats::Damage, 2)
+        .and(&base);
+    let entity = Entity::PLACEHOLDER;
+    let none = QualifierQuery::none();
+    let fire = QualifierQuery::Aggregate(1);
+
+    let querier = OwnedQuerier::new(&stream).join(Burning(true));
+    assert_eq!(querier.eval_stat(entity, &fire, &Stats::Strength), Some(3));
+    assert_eq!(querier.eval_stat(entity, &none, &Stats::Strength), Some(0));
+    assert_eq!(querier.eval_stat(entity, &none, &Stats::Damage), Some(1));
+    // Only the addend is scaled: (10 * 2 + 1) * 2.
+    assert_eq!(querier.eval_stat(entity, &fire, &Stats::Damage), Some(42));
+
+    let querier = OwnedQuerier::new(&stream).join(Burning(false));
+    assert_eq!(querier.eval_stat(entity, &fire, &Stats::Damage), Some(1));
+}
+
+static EVAL_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Counted(i32);
+
+impl StatValue for Counted {
+    type Out = i32;
+
+    fn join(&mut self, other: Self) {
+        self.0 += other.0;
+    }
+
+    fn eval(&self) -> i32 {
+        EVAL_COUNT.fetch_add(1, Ordering::Relaxed);
+        self.0
+    }
+
+    type Add = i32;
+    type Mul = Unsupported;
+    type Bit = Unsupported;
+    type Bounds = Unsupported;
+    type Base = i32;
+
+    fn add(&mut self, other: i32) {
+        self.0 += other;
+    }
+
+    fn from_base(base: i32) -> Self {
+        Counted(base)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Stat)]
+#[stat(value = "Counted")]
+pub struct Expensive;
+
+#[test]
+pub fn eval_cached() {
+    let mut pair = StatValuePair::new(&Expensive, Counted(1));
+    assert!(pair.cached::<Stats>().is_none());
+    let mut cached = pair.cached::<Expensive>().unwrap();
+    assert_eq!(cached.eval(), 1);
+    assert_eq!(cached.eval(), 1);
+    assert_eq!(cached.eval(), 1);
+    assert_eq!(EVAL_COUNT.load(Ordering::Relaxed), 1);
+
+    cached.value_mut().add(2);
+    assert_eq!(cached.value(), &Counted(3));
+    assert_eq!(cached.eval(), 3);
+    assert_eq!(cached.eval(), 3);
+    assert_eq!(EVAL_COUNT.load(Ordering::Relaxed), 2);
+
+    let mut map = StatMap::<u32>::new();
+    map.insert_base(Qualifier::none(), Expensive, 4);
+    map.stream_stat(
+        Entity::PLACEHOLDER,
+        &QualifierQuery::none(),
+        &mut pair,
+        Querier::noop(),
+    );
+    let mut cached = pair.cached::<Expensive>().unwrap();
+    assert_eq!(cached.eval(), 7);
+    assert_eq!(cached.eval(), 7);
+    assert_eq!(EVAL_COUNT.load(Ordering::Relaxed), 3);
+}
+
+/// Damage cannot exceed twice the strength.
+pub struct DamageCap;
+
+impl StatStream for DamageCap {
+    type Qualifier = u32;
+
+    fn finalize(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<u32>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<u32>,
+    ) {
+        if !stat_value.is(&Stats::Damage) {
+            return;
+        }
+        let strength = querier.eval_stat(entity, qualifier, &Stats::Strength);
+        if let (Some(value), Some(strength)) = (stat_value.is_then_cast(&Stats::Damage), strength) {
+            value.max(strength * 2);
+        }
+    }
+}
+
+#[test]
+pub fn finalize() {
+    let mut map = StatMap::<u32>::new();
+    map.insert_base(Qualifier::none(), Stats::Strength, 4);
+    map.insert_base(Qualifier::none(), Stats::Damage, 5);
+    let mut after = StatMap::<u32>::new();
+    after.insert_base(Qualifier::none(), Stats::Damage, 6);
+    after.insert_base(Qualifier::all_of(1), Stats::Strength, 6);
+
+    let entity = Entity::PLACEHOLDER;
+    let querier = OwnedQuerier::new(&map).join(DamageCap).join(&after);
+    assert_eq!(
+        querier.eval_stat(entity, &QualifierQuery::none(), &Stats::Damage),
+        Some(8)
+    );
+    assert_eq!(
+        querier.eval_stat(entity, &QualifierQuery::Aggregate(1), &Stats::Damage),
+        Some(11)
+    );
+    assert_eq!(
+        querier.eval_stat(entity, &QualifierQuery::none(), &Stats::Strength),
+        Some(4)
+    );
+
+    let querier = OwnedQuerier::new(&map).join(Flanking).join(DamageCap);
+    assert_eq!(
+        querier.eval_relation(entity, entity, &QualifierQuery::none(), &Stats::Damage),
+        Some(8)
+    );
+    let querier = OwnedQuerier::new(&map).join(Flanking);
+    assert_eq!(
+        querier.eval_relation(entity, entity, &QualifierQuery::none(), &Stats::Damage),
+        Some(10)
+    );
+}
+
+/// Attacks against a target deal 10 extra damage.
+pub struct Flanking;
+
+impl StatStream for Flanking {
+    type Qualifier = u32;
+
+    fn stream_relation(
+        &self,
+        _: &Self,
+        _: Entity,
+        _: Entity,
+        _: &QualifierQuery<u32>,
+        stat_value: &mut StatValuePair,
+        _: Querier<u32>,
+    ) {
+        if let Some(value) = stat_value.is_then_cast(&Stats::Damage) {
+            value.add(10);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Stat)]
+#[stat(value = "StatFloat<f32>")]
+pub struct Speed;
+
+#[test]
+#[cfg(feature = "json")]
+pub fn describe() {
+    let pair = StatValuePair::new(&Speed, StatFloat::from_base(1.5).with_mul(2.0));
+    let description = pair.describe().unwrap();
+    assert_eq!(description.name, "Speed");
+    assert_eq!(description.value["addend"], 1.5);
+    assert_eq!(description.value["mult"], 2.0);
+    assert!(description.value.get("min").is_some());
+    assert!(description.value.get("max").is_some());
+}
+
+#[test]
+pub fn world_querier() {
+    let mut world = World::new();
+    world.register_stat_max(&Stats::Damage, 10);
+    world.register_stat_relation_for(
+        Stats::Damage,
+        |entity, qualifier, value, querier: Querier<u32>| {
+            value.add(
+                querier
+                    .eval_stat(entity, qualifier, &Stats::Strength)
+                    .unwrap(),
+            )
+        },
+    );
+    let mut map = StatMap::<u32>::new();
+    map.insert_base(Qualifier::none(), Stats::Strength, 4);
+    map.insert_base(Qualifier::none(), Stats::Damage, 3);
+    let a = world.spawn(map).id();
+    let mut map = StatMap::<u32>::new();
+    map.insert_base(Qualifier::none(), Stats::Strength, 8);
+    let b = world.spawn(map).id();
+
+    let querier = OwnedQuerier::from_world(&world, WorldStream::<StatMap<u32>>::new(&world));
+    let none = QualifierQuery::none();
+    assert_eq!(querier.eval_stat(a, &none, &Stats::Damage), Some(7));
+    assert_eq!(querier.eval_stat(b, &none, &Stats::Damage), Some(8));
+    assert_eq!(querier.eval_stat(b, &none, &Stats::Strength), Some(8));
+}
+
+pub const BURNING: u32 = 1;
+pub const FROZEN: u32 = 2;
+
+#[derive(Debug, Clone, Copy, Stat)]
+#[stat(value = "StatFlagsOverride<u32>")]
+pub struct Status;
+
+#[test]
+pub fn flags_override() {
+    let mut burn = StatMap::<u32>::new();
+    burn.insert_base(Qualifier::none(), Status, BURNING | FROZEN);
+    let mut extinguish = StatMap::<u32>::new();
+    extinguish.insert(
+        Qualifier::none(),
+        Status,
+        StatFlagsOverride::default().with_exclude(BURNING),
+    );
+
+    let entity = Entity::PLACEHOLDER;
+    let none = QualifierQuery::none();
+    let querier = OwnedQuerier::new(&burn);
+    assert_eq!(
+        querier.eval_stat(entity, &none, &Status),
+        Some(BURNING | FROZEN)
+    );
+    let querier = OwnedQuerier::new(&burn).join(&extinguish);
+    assert_eq!(querier.eval_stat(entity, &none, &Status), Some(FROZEN));
+    let querier = OwnedQuerier::new(&extinguish).join(&burn);
+    assert_eq!(querier.eval_stat(entity, &none, &Status), Some(FROZEN));
+}
+
+#[test]
+pub fn eval_sweep() {
+    const FIRE: u32 = 1;
+    const ICE: u32 = 2;
+    const LIGHTNING: u32 = 4;
+    let mut defaults = GlobalStatDefaults::new();
+    defaults.insert(Stats::Damage, StatInt::default().with_max(12));
+    let mut map = StatMap::<u32>::new();
+    map.insert_base(Qualifier::none(), Stats::Damage, 5);
+    map.insert_base(Qualifier::all_of(FIRE), Stats::Damage, 3);
+    map.insert_base(Qualifier::all_of(ICE), Stats::Damage, 10);
+
+    let entity = Entity::PLACEHOLDER;
+    let querier = OwnedQuerier::new(&map).with_defaults(&defaults);
+    let qualifiers = [
+        QualifierQuery::Aggregate(FIRE),
+        QualifierQuery::Aggregate(ICE),
+        QualifierQuery::Aggregate(LIGHTNING),
+    ];
+    let sweep = querier.eval_sweep(entity, &qualifiers, &Stats::Damage);
+    assert_eq!(sweep, vec![Some(8), Some(12), Some(5)]);
+    for (qualifier, value) in qualifiers.iter().zip(sweep) {
+        assert_eq!(querier.eval_stat(entity, qualifier, &Stats::Damage), value);
+    }
+}
+
+#[test]
+pub fn eval_with() {
+    let mut map = StatMap::<u32>::new();
+    map.insert_base(Qualifier::none(), Stats::Damage, 2);
+    let mut sword = StatMap::<u32>::new();
+    sword.insert_base(Qualifier::none(), Stats::Damage, 5);
+    let none = QualifierQuery::none();
+
+    let entity = Entity::PLACEHOLDER;
+    let querier = OwnedQuerier::new(&map);
+    assert_eq!(
+        querier.eval_with(&sword, entity, &none, &Stats::Damage),
+        Some(7)
+    );
+    assert_eq!(querier.eval_stat(entity, &none, &Stats::Damage), Some(2));
+
+    let mut world = World::new();
+    let entity = world.spawn((StatEntity, map)).id();
+    world
+        .run_system_once(
+            move |entities: StatEntities<u32>, maps: StatQuery<StatMap<u32>>| {
+                let querier = entities.join(&maps);
+                assert_eq!(
+                    querier.eval_with(&sword, entity, &none, &Stats::Damage),
+                    Some(7)
+                );
+                assert_eq!(querier.eval_stat(entity, &none, &Stats::Damage), Some(2));
+            },
+        )
+        .unwrap();
+}
+
+#[test]
+pub fn base_value() {
+    let mut defaults = GlobalStatDefaults::new();
+    defaults.insert(Stats::Damage, StatInt::from_base(4).with_max(12));
+    let mut map = StatMap::<u32>::new();
+    map.insert_base(Qualifier::none(), Stats::Damage, 5);
+    map.modify(Qualifier::none(), Stats::Damage, Mul(2));
+
+    let entity = Entity::PLACEHOLDER;
+    let querier = OwnedQuerier::new(&map).with_defaults(&defaults);
+    let base = querier.base_value(&Stats::Damage);
+    assert_eq!(base, StatInt::from_base(4).with_max(12));
+    assert_eq!(base.eval(), 4);
+    assert_eq!(
+        querier.eval_stat(entity, &QualifierQuery::none(), &Stats::Damage),
+        Some(12)
+    );
+    assert_eq!(querier.base_value(&Stats::Strength), StatInt::default());
+    assert_eq!(
+        OwnedQuerier::new(&map).base_value(&Stats::Damage),
+        StatInt::default()
+    );
+
+    let mut world = World::new();
+    world.insert_resource(defaults);
+    world
+        .run_system_once(move |entities: StatEntities<u32>| {
+            let querier = entities.join(&map);
+            assert_eq!(querier.base_value(&Stats::Damage).eval(), 4);
+            assert_eq!(
+                querier.eval_stat(entity, &QualifierQuery::none(), &Stats::Damage),
+                Some(12)
+            );
+        })
+        .unwrap();
+}
+
+/// Damage is increased by the strength of a target.
+pub struct Aim(Option<Entity>);
+
+impl StatStream for Aim {
+    type Qualifier = u32;
+
+    fn try_stream_stat(
+        &self,
+        entity: Entity,
+        qualifier: &QualifierQuery<u32>,
+        stat_value: &mut StatValuePair,
+        querier: Querier<u32>,
+    ) -> Result<(), StatError> {
+        if !stat_value.is(&Stats::Damage) {
+            return Ok(());
+        }
+        let target = self.0.ok_or(StatError::Custom("No target.".into()))?;
+        let strength = querier.try_eval_relation(entity, target, qualifier, &Stats::Strength)?;
+        if let Some(value) = stat_value.is_then_cast(&Stats::Damage) {
+            value.add(strength);
+        }
+        Ok(())
+    }
+}
+
+#[test]
+pub fn try_stream_stat() {
+    let mut map = StatMap::<u32>::new();
+    map.insert_base(Qualifier::none(), Stats::Damage, 3);
+    let entity = Entity::PLACEHOLDER;
+    let none = QualifierQuery::none();
+
+    let querier = OwnedQuerier::new(&map).join(Aim(Some(entity)));
+    assert_eq!(querier.try_eval_stat(entity, &none, &Stats::Damage), Ok(3));
+
+    let querier = OwnedQuerier::new(Aim(None)).join(&map);
+    assert_eq!(
+        querier.try_eval_stat(entity, &none, &Stats::Damage),
+        Err(StatError::Custom("No target.".into()))
+    );
+    assert_eq!(querier.eval_stat(entity, &none, &Stats::Damage), Some(3));
+    assert_eq!(
+        querier.try_eval_stat(entity, &none, &Stats::Strength),
+        Ok(0)
+    );
+
+    let mut pair = StatValuePair::new_default(&Stats::Damage);
+    assert_eq!(
+        Aim(Some(entity)).try_stream_stat(entity, &none, &mut pair, Querier::noop()),
+        Err(StatError::RelationFailed {
+            from: entity,
+            to: entity,
+            stat: "Strength",
+        })
+    );
+}
+
+/// Strength of or against a cursed entity cannot be queried.
+#[derive(Component)]
+pub struct Cursed(bool);
+
+impl StatStream for Cursed {
+    type Qualifier = u32;
+
+    fn try_stream_stat(
+        &self,
+        _: Entity,
+        _: &QualifierQuery<u32>,
+        stat_value: &mut StatValuePair,
+        _: Querier<u32>,
+    ) -> Result<(), StatError> {
+        if self.0 && stat_value.is(&Stats::Strength) {
+            return Err(StatError::Custom("Cursed.".into()));
+        }
+        Ok(())
+    }
+
+    fn try_stream_relation(
+        &self,
+        other: &Self,
+        _: Entity,
+        _: Entity,
+        _: &QualifierQuery<u32>,
+        stat_value: &mut StatValuePair,
+        _: Querier<u32>,
+    ) -> Result<(), StatError> {
+        if other.0 && stat_value.is(&Stats::Strength) {
+            return Err(StatError::Custom("Target cursed.".into()));
+        }
+        Ok(())
+    }
+}
+
+#[test]
+pub fn try_stream_query() {
+    let mut world = World::new();
+    let player = world.spawn((StatEntity, Cursed(false))).id();
+    let ally = world.spawn((StatEntity, Cursed(false))).id();
+    let enemy = world.spawn((StatEntity, Cursed(true))).id();
+    let minion = world.spawn(StatEntity).id();
+    world.entity_mut(enemy).add_child(minion);
+    let mut map = StatMap::<u32>::new();
+    map.insert_base(Qualifier::none(), Stats::Damage, 3);
+
+    world
+        .run_system_once(
+            move |entities: StatEntities<u32>,
+                  cursed: StatQuery<Cursed>,
+                  parents: ParentQuery<Cursed>| {
+                let none = QualifierQuery::none();
+                let querier = entities.join(&cursed).join(&parents).join(&map);
+                assert_eq!(
+                    querier.try_eval_stat(player, &none, &Stats::Strength),
+                    Ok(0)
+                );
+                assert_eq!(
+                    querier.try_eval_stat(enemy, &none, &Stats::Strength),
+                    Err(StatError::Custom("Cursed.".into()))
+                );
+                assert_eq!(
+                    querier.try_eval_stat(minion, &none, &Stats::Strength),
+                    Err(StatError::Custom("Cursed.".into()))
+                );
+
+                let aim = |target| entities.join(&cursed).join(&map).join(Aim(Some(target)));
+                assert_eq!(
+                    aim(ally).try_eval_stat(player, &none, &Stats::Damage),
+                    Ok(3)
+                );
+                assert_eq!(
+                    aim(enemy).try_eval_stat(player, &none, &Stats::Damage),
+                    Err(StatError::Custom("Target cursed.".into()))
+                );
+                assert_eq!(aim(enemy).eval_stat(player, &none, &Stats::Damage), Some(3));
+                assert_eq!(
+                    aim(player).try_eval_stat(enemy, &none, &Stats::Damage),
+                    Ok(3)
+                );
+            },
+        )
+        .unwrap();
+}
+
+#[test]
+pub fn try_stream_despawned() {
+    let mut world = World::new();
+    let player = world.spawn((StatEntity, Cursed(false))).id();
+    let bystander = world.spawn(StatEntity).id();
+    let target = world.spawn((StatEntity, Cursed(false))).id();
+    world.despawn(target);
+
+    world
+        .run_system_once(
+            move |entities: StatEntities<u32>, cursed: StatQuery<Cursed>| {
+                let none = QualifierQuery::none();
+                let querier = entities.join(&cursed);
+                assert_eq!(
+                    querier.try_eval_relation(player, bystander, &none, &Stats::Damage),
+                    Ok(0)
+                );
+                assert_eq!(
+                    querier.try_eval_relation(player, target, &none, &Stats::Damage),
+                    Err(StatError::RelationFailed {
+                        from: player,
+                        to: target,
+                        stat: Stats::Damage.name(),
+                    })
+                );
+                assert_eq!(
+                    querier.eval_relation(player, target, &none, &Stats::Damage),
+                    Some(0)
+                );
+                assert_eq!(
+                    querier.try_eval_stat(target, &none, &Stats::Damage),
+                    Err(StatError::QueryFailed {
+                        entity: target,
+                        stat: Stats::Damage.name(),
+                    })
+                );
+            },
+        )
+        .unwrap();
+
+    let none = QualifierQuery::none();
+    let querier = OwnedQuerier::from_world(&world, WorldStream::<Cursed>::new(&world));
+    assert_eq!(
+        querier.try_eval_relation(player, bystander, &none, &Stats::Damage),
+        Ok(0)
+    );
+    assert_eq!(
+        querier.try_eval_relation(player, target, &none, &Stats::Damage),
+        Err(StatError::RelationFailed {
+            from: player,
+            to: target,
+            stat: Stats::Damage.name(),
+        })
+    );
+}
+
+#[derive(Component)]
+pub struct Aura(i32);
+
+impl QueryStream for Aura {
+    type Qualifier = u32;
+    type Query = &'static Aura;
+    type Context = ();
+
+    fn stream_stat(
+        aura: &Aura,
+        _: &(),
+        _: Entity,
+        _: &QualifierQuery<u32>,
+        stat_value: &mut StatValuePair,
+        _: Querier<u32>,
+    ) {
+        if let Some(value) = stat_value.is_then_cast(&Stats::Strength) {
+            value.add(aura.0)
+        }
+    }
+}
+
+#[test]
+pub fn parent_query() {
+    let mut world = World::new();
+    let child = world.spawn(StatEntity).id();
+    let orphan = world.spawn(StatEntity).id();
+    let parent = world.spawn((StatEntity, Aura(3))).add_child(child).id();
+
+    world
+        .run_system_once(
+            move |entities: StatEntities<u32>, auras: ParentQuery<Aura>| {
+                let querier = entities.join(&auras);
+                let query = QualifierQuery::none();
+                assert_eq!(querier.eval_stat(child, &query, &Stats::Strength), Some(3));
+                assert_eq!(querier.eval_stat(orphan, &query, &Stats::Strength), Some(0));
+                assert_eq!(querier.eval_stat(parent, &query, &Stats::Strength), Some(0));
+            },
+        )
+        .unwrap();
+}
+
+static WARD_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Component)]
+pub struct Ward(i32);
+
+impl QueryStream for Ward {
+    type Qualifier = u32;
+    type Query = &'static Ward;
+    type Context = ();
+
+    fn stream_stat(
+        ward: &Ward,
+        _: &(),
+        _: Entity,
+        _: &QualifierQuery<u32>,
+        stat_value: &mut StatValuePair,
+        _: Querier<u32>,
+    ) {
+        WARD_COUNT.fetch_add(1, Ordering::Relaxed);
+        if let Some(value) = stat_value.is_then_cast(&Stats::Strength) {
+            value.add(ward.0)
+        }
+    }
+
+    fn applies_to(stat: StatInst) -> bool {
+        stat.is(&Stats::Strength)
+    }
+}
+
+#[test]
+pub fn query_applies_to() {
+    let mut world = World::new();
+    let mut map = StatMap::<u32>::new();
+    map.insert_base(Qualifier::none(), Stats::Strength, 4);
+    let entity = world.spawn((StatEntity, Ward(3), map)).id();
+
+    world
+        .run_system_once(
+            move |entities: StatEntities<u32>,
+                  wards: StatQuery<Ward>,
+                  maps: StatQuery<StatMap<u32>>| {
+                let query = QualifierQuery::none();
+                let querier = entities.join(&wards);
+                assert_eq!(querier.eval_stat(entity, &query, &Stats::Damage), Some(0));
+                assert_eq!(WARD_COUNT.load(Ordering::Relaxed), 0);
+                assert_eq!(querier.eval_stat(entity, &query, &Stats::Strength), Some(3));
+                assert_eq!(WARD_COUNT.load(Ordering::Relaxed), 1);
+
+                let querier = entities.join(&maps);
+                assert_eq!(querier.eval_stat(entity, &query, &Stats::Strength), Some(4));
+                assert_eq!(querier.eval_stat(entity, &query, &Stats::Damage), Some(0));
+            },
+        )
+        .unwrap();
+}
+
+#[test]
+pub fn join_from() {
+    let buff = StatValuePair::new(&Stats::Damage, StatInt::from_base(2).with_mul(3));
+    let mut pair = StatValuePair::new(&Stats::Damage, StatInt::from_base(5));
+    assert_eq!(pair.cached::<Stats>().unwrap().eval(), 5);
+    assert!(pair.join_from(&buff));
+    assert_eq!(pair.cached::<Stats>().unwrap().eval(), 21);
+    assert!(!pair.join_from(&StatValuePair::new_default(&Stats::Strength)));
+    assert_eq!(
+        pair.into_result::<Stats>(),
+        Some(StatInt::from_base(7).with_mul(3))
+    );
+}
+
+#[derive(Debug, Clone, Copy, Stat)]
+#[stat(value = "StatAll")]
+pub struct CanCast;
+
+#[test]
+pub fn stat_all() {
+    assert!(StatAll::default().eval());
+    assert!(!StatAll::default().with_join(StatAll(false)).eval());
+    assert!(!StatAll::from_base(true).with_or(false).eval());
+    assert!(StatAll(true).with_join(StatAll(true)).eval());
+
+    let entity = Entity::PLACEHOLDER;
+    let defaults = GlobalStatDefaults::new();
+    let mut map = StatMap::<u32>::new();
+    map.insert_base(Qualifier::all_of(1), CanCast, true);
+    map.insert_base(Qualifier::all_of(2), CanCast, false);
+
+    let querier = OwnedQuerier::new(&map).with_defaults(&defaults);
+    let querier = querier.querier();
+    assert_eq!(
+        querier.eval_stat(entity, &QualifierQuery::none(), &CanCast),
+        Some(true)
+    );
+    assert_eq!(
+        querier.eval_stat(entity, &QualifierQuery::Aggregate(1), &CanCast),
+        Some(true)
+    );
+    assert_eq!(
+        querier.eval_stat(entity, &QualifierQuery::Aggregate(3), &CanCast),
+        Some(false)
+    );
+
+    let json = serde_json::to_string(&StatAll(false)).unwrap();
+    assert_eq!(
+        serde_json::from_str::<StatAll>(&json).unwrap(),
+        StatAll(false)
+    );
+}
@@ -0,0 +1,120 @@
+use std::any::TypeId;
+
+use bevy_app::App;
+use bevy_ecs::reflect::AppTypeRegistry;
+use bevy_reflect::{List, ReflectMut};
+use bevy_stat_query::{
+    operations::StatOperation::Mul,
+    types::{StatFloat, StatInt, StatIntPercent, StatIntRounded},
+    Fraction, Qualifier, Stat, StatExtension, StatMap, StatValue,
+};
+
+#[test]
+pub fn register_reflect_types() {
+    let mut app = App::new();
+    app.register_stat_reflect_types::<u32>();
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+    assert!(registry.contains(TypeId::of::<StatFloat<f32>>()));
+    assert!(registry.contains(TypeId::of::<StatInt<i32>>()));
+    assert!(registry.contains(TypeId::of::<StatIntPercent<i32>>()));
+    assert!(registry.contains(TypeId::of::<StatIntRounded<i32, f32>>()));
+    assert!(registry.contains(TypeId::of::<Fraction<i32>>()));
+    assert!(registry.contains(TypeId::of::<StatMap<u32>>()));
+    assert!(!registry.contains(TypeId::of::<StatMap<u64>>()));
+    assert!(registry.get_with_short_type_path("StatInt<i32>").is_some());
+}
+
+#[derive(Debug, Clone, Copy, Stat)]
+#[stat(value = "StatInt<i32>")]
+pub struct Health;
+
+#[derive(Debug, Clone, Copy, Stat)]
+#[stat(value = "StatInt<i16>")]
+pub struct Unregistered;
+
+#[test]
+pub fn reflect_entries() {
+    let mut app = App::new();
+    app.register_stat_reflect_types::<u32>();
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+
+    let none = Qualifier::<u32>::none();
+    let mut map = StatMap::new();
+    map.insert_base(none, Health, 5);
+    map.modify(none, Health, Mul(2));
+    map.insert_base(none, Unregistered, 1);
+
+    let mut view = map.reflect_entries(&registry);
+    assert_eq!(view.entries.len(), 2);
+    let entry = view
+        .entries
+        .iter_mut()
+        .find(|x| x.name == "Health")
+        .unwrap();
+    assert_eq!(*entry.qualifier, none);
+    let ReflectMut::Struct(value) = entry.value.as_mut().unwrap().reflect_mut() else {
+        panic!("Expected a struct.")
+    };
+    *value
+        .field_mut("addend")
+        .unwrap()
+        .try_downcast_mut::<i32>()
+        .unwrap() = 7;
+    let entry = view
+        .entries
+        .iter()
+        .find(|x| x.name == "Unregistered")
+        .unwrap();
+    assert!(entry.value.is_none());
+
+    assert_eq!(map.get_evaled(&none, &Health), Some(14));
+    assert_eq!(
+        map.get(&none, &Health),
+        Some(&StatInt::from_base(7).with_mul(2))
+    );
+}
+
+#[test]
+pub fn reflect_entries_dynamic() {
+    let mut app = App::new();
+    app.register_stat_reflect_types::<u32>();
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+
+    let none = Qualifier::<u32>::none();
+    let mut map = StatMap::new();
+    map.insert_base(none, Health, 5);
+    map.insert_base(none, Unregistered, 1);
+
+    let mut view = map.reflect_entries(&registry);
+    let mut list = view.to_dynamic();
+    assert_eq!(list.len(), 2);
+    for index in 0..list.len() {
+        let ReflectMut::Struct(item) = list.get_mut(index).unwrap().reflect_mut() else {
+            panic!("Expected a struct.")
+        };
+        let name = item.field("name").unwrap().try_downcast_ref::<String>();
+        if name.unwrap() == "Unregistered" {
+            assert!(item.field("value").is_none());
+            continue;
+        }
+        assert_eq!(
+            item.field("qualifier")
+                .unwrap()
+                .try_downcast_ref::<Qualifier<u32>>(),
+            Some(&none)
+        );
+        let ReflectMut::Struct(value) = item.field_mut("value").unwrap().reflect_mut() else {
+            panic!("Expected a struct.")
+        };
+        *value
+            .field_mut("addend")
+            .unwrap()
+            .try_downcast_mut::<i32>()
+            .unwrap() = 9;
+    }
+    view.apply(&list).unwrap();
+    list.pop();
+    assert!(view.apply(&list).is_err());
+
+    assert_eq!(map.get_evaled(&none, &Health), Some(9));
+}
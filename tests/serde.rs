@@ -3,7 +3,8 @@ use bevy_reflect::TypePath;
 use bevy_serde_lens::{BevyObject, DefaultInit, WorldExtension};
 use bevy_stat_query::StatVTable;
 use bevy_stat_query::{
-    operations::StatOperation, types::*, Fraction, Qualifier, Stat, StatExtension, StatMap,
+    operations::StatOperation, types::*, Fraction, Qualifier, QualifierQuery, Stat, StatExtension,
+    StatMap,
 };
 use serde::{Deserialize, Serialize};
 
@@ -61,7 +62,8 @@ impl_stat!(
     SIntPct: StatIntPercent<i32>,
     SIntFrac: StatIntRounded<i8, Fraction<i8>>,
     SMul: StatMult<f32>,
-    SFracMul: StatMult<Fraction<i32>>
+    SFracMul: StatMult<Fraction<i32>>,
+    SStacking: StatStacking<i32, 5>
 );
 
 #[derive(Debug, Component, Serialize, Deserialize, Default, TypePath)]
@@ -97,6 +99,49 @@ pub struct Full {
     map: StatMap<bool>,
 }
 
+#[derive(Debug, Component, Serialize, Deserialize, TypePath)]
+#[serde(transparent)]
+pub struct VersionedMap(
+    #[serde(
+        serialize_with = "StatMap::serialize_versioned",
+        deserialize_with = "StatMap::deserialize_versioned"
+    )]
+    StatMap<bool>,
+);
+
+#[derive(Debug, Component, Serialize, Deserialize, Default, TypePath)]
+pub struct VersionedMarker;
+
+#[derive(Debug, BevyObject)]
+#[serde(transparent)]
+pub struct Versioned {
+    #[serde(skip)]
+    marker: DefaultInit<VersionedMarker>,
+    map: VersionedMap,
+}
+
+fn serialize_permanent<S: serde::Serializer>(
+    map: &StatMap<bool>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    map.serialize_filtered(serializer, |stat| !stat.is(&SFloat32))
+}
+
+#[derive(Debug, Component, Serialize, Deserialize, TypePath)]
+#[serde(transparent)]
+pub struct PermanentMap(#[serde(serialize_with = "serialize_permanent")] StatMap<bool>);
+
+#[derive(Debug, Component, Serialize, Deserialize, Default, TypePath)]
+pub struct PermanentMarker;
+
+#[derive(Debug, BevyObject)]
+#[serde(transparent)]
+pub struct Permanent {
+    #[serde(skip)]
+    marker: DefaultInit<PermanentMarker>,
+    map: PermanentMap,
+}
+
 #[test]
 pub fn serde_test() {
     let mut world = World::new();
@@ -213,3 +258,139 @@ pub fn serde_test() {
     let result2 = vec2.output.finalize().unwrap();
     assert_eq!(result, result2);
 }
+
+#[test]
+pub fn serde_versioned_test() {
+    let mut world = World::new();
+    world.register_stat::<SInt>();
+    world.register_stat::<SFloat32>();
+
+    let q_false = Qualifier::all_of(false);
+    // Simulate a save from before versioning, which serializes `StatMap` directly.
+    world.spawn((FullMarker, {
+        let mut map = StatMap::new();
+        map.insert_base(q_false, SInt, -4);
+        map.insert_base(q_false, SFloat32, 3.5);
+        map
+    }));
+    let v0 = world
+        .save::<Full, _>(serde_json::value::Serializer)
+        .unwrap();
+    world.despawn_bound_objects::<Full>();
+
+    world.load::<Versioned, _>(&v0).unwrap();
+    let value = world
+        .save::<Versioned, _>(serde_json::value::Serializer)
+        .unwrap();
+    assert_ne!(value, v0);
+    world.despawn_bound_objects::<Versioned>();
+    world.load::<Versioned, _>(&value).unwrap();
+    let value2 = world
+        .save::<Versioned, _>(serde_json::value::Serializer)
+        .unwrap();
+    assert_eq!(value, value2);
+
+    let map = &world.query::<&VersionedMap>().single(&world).0;
+    assert_eq!(map.get_evaled(&q_false, &SInt), Some(-4));
+    assert_eq!(map.get_evaled(&q_false, &SFloat32), Some(3.5));
+}
+
+#[test]
+pub fn serde_filtered_test() {
+    let mut world = World::new();
+    world.register_stat::<SInt>();
+    world.register_stat::<SFloat32>();
+
+    let q_false = Qualifier::all_of(false);
+    world.spawn((PermanentMarker, {
+        let mut map = StatMap::new();
+        map.insert_base(q_false, SInt, -4);
+        map.insert_base(q_false, SFloat32, 3.5);
+        PermanentMap(map)
+    }));
+    let value = world
+        .save::<Permanent, _>(serde_json::value::Serializer)
+        .unwrap();
+    world.despawn_bound_objects::<Permanent>();
+    world.load::<Permanent, _>(&value).unwrap();
+
+    let map = &world.query::<&PermanentMap>().single(&world).0;
+    assert_eq!(map.len(), 1);
+    assert_eq!(map.get_evaled(&q_false, &SInt), Some(-4));
+    assert_eq!(map.get_evaled(&q_false, &SFloat32), None);
+}
+
+#[test]
+pub fn serde_stacking_test() {
+    let mut world = World::new();
+    world.register_stat::<SStacking>();
+
+    let q_false = Qualifier::all_of(false);
+    world.spawn((FullMarker, {
+        let mut map = StatMap::new();
+        map.insert(q_false, SStacking, StatStacking::new(2, 3));
+        map.modify(q_false, SStacking, StatOperation::Add(6));
+        map.modify(q_false, SStacking, StatOperation::Add(6));
+        map.modify(q_false, SStacking, StatOperation::Add(6));
+        map
+    }));
+    let value = world
+        .save::<Full, _>(serde_json::value::Serializer)
+        .unwrap();
+    world.despawn_bound_objects::<Full>();
+    world.load::<Full, _>(&value).unwrap();
+    let value2 = world
+        .save::<Full, _>(serde_json::value::Serializer)
+        .unwrap();
+    assert_eq!(value, value2);
+
+    let map = world.query::<&StatMap<bool>>().single(&world);
+    let stacking = map.get(&q_false, &SStacking).unwrap();
+    assert_eq!(stacking.stacks(), 6);
+    assert_eq!(stacking.amount(), 24);
+    assert_eq!(map.get_evaled(&q_false, &SStacking), Some(20));
+}
+
+#[test]
+pub fn eval_all_test() {
+    let q_false = Qualifier::all_of(false);
+    let mut map = StatMap::new();
+    map.insert_base(q_false, SInt, -4);
+    map.insert_base(q_false, SUInt, 7);
+    map.insert_base(q_false, SFloat32, 3.5);
+    map.insert_base(q_false, SFlags, MyFlags::F);
+    map.insert_base(q_false, SString, "Ferris the Rustacean".into());
+    map.insert_base(q_false, SIntFrac, 69);
+    map.insert_base(q_false, SIntPct, 420);
+    map.insert_base(q_false, SMul, 1.5);
+    map.insert_base(q_false, SFracMul, Fraction::new(44, 57));
+    map.insert_base(Qualifier::all_of(true), SInt, 100);
+
+    let query = QualifierQuery::Aggregate(false);
+    let mut result: Vec<_> = map
+        .eval_all(&query)
+        .into_iter()
+        .map(|(name, value)| (name, format!("{value:?}")))
+        .collect();
+    result.sort();
+    assert_eq!(
+        result,
+        [
+            ("SFlags", format!("{:?}", MyFlags::F)),
+            ("SFloat32", "3.5".to_owned()),
+            ("SFracMul", format!("{:?}", Fraction::new(44, 57))),
+            ("SInt", "-4".to_owned()),
+            ("SIntFrac", "69".to_owned()),
+            ("SIntPct", format!("{:?}", map.eval_stat(&query, &SIntPct))),
+            ("SMul", "1.5".to_owned()),
+            ("SString", "\"Ferris the Rustacean\"".to_owned()),
+            ("SUInt", "7".to_owned()),
+        ]
+    );
+
+    let result = map.eval_all(&QualifierQuery::Aggregate(true));
+    assert_eq!(result.len(), 9);
+    assert!(result
+        .iter()
+        .any(|(name, value)| *name == "SInt" && format!("{value:?}") == "96"));
+}
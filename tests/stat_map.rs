@@ -0,0 +1,803 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use bevy_stat_query::{
+    operations::StatOperation::{Add, Mul},
+    stat_struct,
+    types::{Prioritized, StatFlags, StatFloat, StatInt},
+    Qualifier, QualifierQuery, Stat, StatInst, StatMap, StatMapRecorder, StatVTable, StatValue,
+    StatVisitor,
+};
+
+#[derive(Debug, Clone, Copy, Stat)]
+#[stat(value = "StatInt<i32>")]
+pub enum IntStats {
+    A,
+    B,
+    C,
+}
+
+#[derive(Debug, Clone, Copy, Stat)]
+#[stat(value = "StatFloat<f32>")]
+pub struct FloatStat;
+
+#[derive(Debug, Clone, Copy, Stat)]
+#[stat(value = "StatFlags<u32>")]
+pub struct FlagsStat;
+
+#[derive(Debug, Clone, Copy, Stat)]
+#[stat(value = "Prioritized<Box<str>>")]
+pub struct Title;
+
+#[test]
+pub fn recorder_undo_redo() {
+    let none = Qualifier::<u32>::none();
+    let one = Qualifier::all_of(1u32);
+
+    let mut map = StatMap::new();
+    map.insert_base(none, IntStats::A, 1);
+    map.insert_base(one, IntStats::B, 2);
+    let initial = format!("{map:?}");
+
+    let mut recorder = StatMapRecorder::new(map);
+    recorder.insert(none, IntStats::A, Default::default());
+    recorder.modify(one, IntStats::B, Add(5));
+    recorder.modify(none, IntStats::C, Mul(3));
+    recorder.modify(one, FloatStat, Add(1.5));
+    assert_eq!(recorder.remove(&one, &IntStats::B).unwrap().eval(), 7);
+    let edited = format!("{:?}", recorder.map());
+    assert_ne!(initial, edited);
+
+    while recorder.undo() {}
+    assert!(!recorder.can_undo());
+    assert_eq!(format!("{:?}", recorder.map()), initial);
+
+    while recorder.redo() {}
+    assert!(!recorder.can_redo());
+    assert_eq!(format!("{:?}", recorder.map()), edited);
+
+    recorder.undo();
+    assert_eq!(recorder.map().get_evaled(&one, &IntStats::B), Some(7));
+    recorder.modify(none, IntStats::A, Add(2));
+    assert!(!recorder.can_redo());
+}
+
+#[test]
+pub fn coalesce() {
+    let none = Qualifier::<u32>::none();
+    let one = Qualifier::all_of(1u32);
+
+    let mut map: StatMap<u32> = [
+        (none, IntStats::A, StatInt::from_base(1)),
+        (none, IntStats::A, StatInt::from_base(2)),
+        (one, IntStats::B, StatInt::from_base(5)),
+        (none, IntStats::A, StatInt::from_base(4)),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(map.len(), 4);
+    let query = QualifierQuery::Aggregate(1u32);
+    let expected = map.eval_stat(&query, &IntStats::A);
+
+    map.coalesce();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get_evaled(&none, &IntStats::A), Some(7));
+    assert_eq!(map.get_evaled(&one, &IntStats::B), Some(5));
+    assert_eq!(map.eval_stat(&query, &IntStats::A), expected);
+}
+
+#[test]
+pub fn get_many_mut() {
+    let none = Qualifier::<u32>::none();
+    let one = Qualifier::all_of(1u32);
+
+    let mut map = StatMap::new();
+    map.insert_base(none, IntStats::A, 1);
+    map.insert_base(none, IntStats::B, 2);
+    map.insert_base(one, IntStats::A, 3);
+
+    let [a, b, c, d] = map
+        .get_many_mut([
+            (&none, &IntStats::A),
+            (&none, &IntStats::B),
+            (&one, &IntStats::A),
+            (&one, &IntStats::C),
+        ])
+        .unwrap();
+    assert!(d.is_none());
+    a.unwrap().add(10);
+    b.unwrap().add(20);
+    c.unwrap().add(30);
+    assert_eq!(map.get_evaled(&none, &IntStats::A), Some(11));
+    assert_eq!(map.get_evaled(&none, &IntStats::B), Some(22));
+    assert_eq!(map.get_evaled(&one, &IntStats::A), Some(33));
+
+    assert!(map
+        .get_many_mut([
+            (&none, &IntStats::A),
+            (&one, &IntStats::A),
+            (&none, &IntStats::A)
+        ])
+        .is_none());
+    assert!(map
+        .get_many_mut([(&one, &IntStats::C), (&one, &IntStats::C)])
+        .is_none());
+}
+
+#[test]
+pub fn entries_round_trip() {
+    let none = Qualifier::<u32>::none();
+    let one = Qualifier::all_of(1u32);
+
+    let mut map = StatMap::new();
+    map.insert_base(one, IntStats::B, 2);
+    map.insert_base(none, IntStats::A, 1);
+    map.insert_base(none, FloatStat, 1.5);
+    let initial = format!("{map:?}");
+
+    let mut entries = map.into_entries();
+    assert_eq!(entries.len(), 3);
+    assert!(entries
+        .iter()
+        .any(|(q, pair)| *q == one && pair.is(&IntStats::B)));
+    entries.reverse();
+
+    let map = StatMap::from_entries(entries);
+    assert_eq!(format!("{map:?}"), initial);
+    assert_eq!(map.get_evaled(&one, &IntStats::B), Some(2));
+    assert_eq!(map.get_evaled(&none, &FloatStat), Some(1.5));
+
+    let (qualifier, pair) = map.into_entries().pop().unwrap();
+    assert_eq!(qualifier, none);
+    assert_eq!(pair.into_result::<FloatStat>().unwrap().eval(), 1.5);
+}
+
+#[test]
+pub fn canonical_serialization() {
+    let none = Qualifier::<u32>::none();
+    let a = StatInt::<i32>::from_base(3).with_min(10).with_max(5);
+    let b = StatInt::<i32>::from_base(3).with_max(4).with_min(10);
+    assert_ne!(a, b);
+    assert!(!a.is_canonical());
+    assert_eq!(a.eval(), b.eval());
+
+    let (mut c, mut d) = (a, b);
+    c.canonicalize();
+    d.canonicalize();
+    assert_eq!(c, d);
+    assert!(c.is_canonical());
+    assert_eq!(c.eval(), a.eval());
+    assert_eq!(c.is_clamped(), a.is_clamped());
+    assert_eq!(c.into_base(), a.into_base());
+    assert_eq!(c.with_add(100).eval(), a.with_add(100).eval());
+
+    let mut map_a = StatMap::new();
+    map_a.insert(none, IntStats::A, a);
+    let mut map_b = StatMap::new();
+    map_b.insert(none, IntStats::A, b);
+    let json = serde_json::to_string(&map_a).unwrap();
+    assert_eq!(json, serde_json::to_string(&map_b).unwrap());
+    assert!(json.contains(&serde_json::to_string(&c).unwrap()));
+    assert!(!json.contains(&serde_json::to_string(&a).unwrap()));
+    assert_eq!(map_a.get(&none, &IntStats::A), Some(&a));
+
+    // Canonical values are serialized as is.
+    let e = StatInt::<i32>::from_base(3).with_min(1).with_max(5);
+    let mut map_e = StatMap::new();
+    map_e.insert(none, IntStats::A, e);
+    assert!(serde_json::to_string(&map_e)
+        .unwrap()
+        .contains(&serde_json::to_string(&e).unwrap()));
+}
+
+#[derive(Default)]
+pub struct CountVisitor {
+    counts: BTreeMap<&'static str, usize>,
+    int_sum: i64,
+    float_sum: f64,
+}
+
+impl StatVisitor<u32> for CountVisitor {
+    fn visit_int(&mut self, _: &Qualifier<u32>, stat: StatInst, value: i64) {
+        *self.counts.entry(stat.name()).or_default() += 1;
+        self.int_sum += value;
+    }
+
+    fn visit_float(&mut self, _: &Qualifier<u32>, stat: StatInst, value: f64) {
+        *self.counts.entry(stat.name()).or_default() += 1;
+        self.float_sum += value;
+    }
+}
+
+#[test]
+pub fn visit() {
+    let none = Qualifier::<u32>::none();
+    let one = Qualifier::all_of(1u32);
+
+    let mut map = StatMap::new();
+    map.insert_base(none, IntStats::A, 1);
+    map.insert_base(one, IntStats::A, 2);
+    map.modify(none, IntStats::B, Mul(3));
+    map.insert_base(none, FloatStat, 1.5);
+    map.insert_base(one, FloatStat, 2.0);
+
+    let mut visitor = CountVisitor::default();
+    map.visit(&mut visitor);
+    assert_eq!(
+        visitor.counts.into_iter().collect::<Vec<_>>(),
+        vec![("A", 2), ("B", 1), ("FloatStat", 2)]
+    );
+    assert_eq!(visitor.int_sum, 3);
+    assert_eq!(visitor.float_sum, 3.5);
+}
+
+#[test]
+pub fn query_stat_explained() {
+    let none = Qualifier::<u32>::none();
+    let one = Qualifier::all_of(1u32);
+    let two = Qualifier::all_of(2u32);
+
+    let mut map = StatMap::new();
+    map.insert_base(none, IntStats::A, 1);
+    map.insert_base(one, IntStats::A, 2);
+    map.insert_base(two, IntStats::A, 4);
+    map.insert_base(one, IntStats::B, 8);
+
+    let query = QualifierQuery::Aggregate(1u32);
+    let (value, qualifiers) = map.query_stat_explained(&query, &IntStats::A);
+    assert_eq!(value.eval(), 3);
+    assert_eq!(value.eval(), map.eval_stat(&query, &IntStats::A));
+    assert_eq!(qualifiers, vec![none, one]);
+
+    let (value, qualifiers) = map.query_stat_explained(&query, &IntStats::C);
+    assert_eq!(value.eval(), 0);
+    assert!(qualifiers.is_empty());
+}
+
+#[test]
+pub fn insert_many() {
+    let none = Qualifier::<u32>::none();
+    let one = Qualifier::all_of(1u32);
+
+    let mut map = StatMap::new();
+    map.insert_base(none, IntStats::A, 1);
+    map.insert_base(one, IntStats::C, 5);
+    map.insert_many([
+        (one, IntStats::B, StatInt::from_base(2)),
+        (none, IntStats::A, StatInt::from_base(3)),
+        (one, IntStats::B, StatInt::from_base(4)),
+        (none, IntStats::C, StatInt::from_base(6)),
+    ]);
+    assert_eq!(map.len(), 4);
+    assert_eq!(map.get_evaled(&none, &IntStats::A), Some(3));
+    assert_eq!(map.get_evaled(&one, &IntStats::B), Some(4));
+    assert_eq!(map.get_evaled(&one, &IntStats::C), Some(5));
+    assert_eq!(map.get_evaled(&none, &IntStats::C), Some(6));
+}
+
+#[derive(Debug, Clone, Copy, Stat)]
+#[stat(value = "StatInt<i32>")]
+pub enum Buffs {
+    BuffStrength,
+    BuffSpeed,
+    Debuff,
+}
+
+#[test]
+pub fn remove_by_name() {
+    let none = Qualifier::<u32>::none();
+    let one = Qualifier::all_of(1u32);
+
+    let mut map = StatMap::new();
+    map.insert_base(none, Buffs::BuffStrength, 1);
+    map.insert_base(one, Buffs::BuffStrength, 2);
+    map.insert_base(none, Buffs::BuffSpeed, 3);
+    map.insert_base(none, Buffs::Debuff, 4);
+    map.insert_base(one, IntStats::A, 5);
+
+    map.remove_by_name(|name| name.starts_with("Buff"));
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get_evaled(&none, &Buffs::BuffStrength), None);
+    assert_eq!(map.get_evaled(&one, &Buffs::BuffStrength), None);
+    assert_eq!(map.get_evaled(&none, &Buffs::BuffSpeed), None);
+    assert_eq!(map.get_evaled(&none, &Buffs::Debuff), Some(4));
+    assert_eq!(map.get_evaled(&one, &IntStats::A), Some(5));
+}
+
+#[test]
+pub fn checkpoint_rollback() {
+    let none = Qualifier::<u32>::none();
+    let one = Qualifier::all_of(1u32);
+
+    let mut map = StatMap::new();
+    map.insert_base(none, IntStats::A, 1);
+    map.insert_base(one, IntStats::B, 2);
+    let initial = format!("{map:?}");
+
+    let checkpoint = map.checkpoint();
+    map.modify(none, IntStats::A, Mul(3));
+    map.insert_base(one, IntStats::C, 5);
+    map.remove(&one, &IntStats::B);
+    map.insert_base(none, FloatStat, 1.5);
+    assert_ne!(format!("{map:?}"), initial);
+
+    map.rollback(checkpoint.clone());
+    assert_eq!(format!("{map:?}"), initial);
+    assert_eq!(map.get_evaled(&none, &IntStats::A), Some(1));
+    assert_eq!(map.get_evaled(&one, &IntStats::B), Some(2));
+    assert_eq!(map.get_evaled(&one, &IntStats::C), None);
+
+    map.remove_all(&IntStats::A);
+    map.rollback(checkpoint);
+    assert_eq!(format!("{map:?}"), initial);
+}
+
+#[test]
+pub fn rebase() {
+    let none = Qualifier::<u32>::none();
+    let one = Qualifier::all_of(1u32);
+
+    let old = StatInt::<i32>::from_base(10);
+    let new = StatInt::<i32>::from_base(15);
+    let mut map = StatMap::new();
+    map.insert(none, IntStats::A, old);
+    map.insert(one, IntStats::A, old.with_mul(2));
+    map.insert_base(none, IntStats::B, 10);
+    let fire = QualifierQuery::Aggregate(1u32);
+    assert_eq!(map.eval_stat(&fire, &IntStats::A), 40);
+
+    map.rebase(&IntStats::A, &old, &new);
+    assert_eq!(map.get_evaled(&none, &IntStats::A), Some(15));
+    assert_eq!(map.get_evaled(&one, &IntStats::A), Some(20));
+    assert_eq!(map.get_evaled(&none, &IntStats::B), Some(10));
+    // The new default is only counted once: (15 + 10) * 2.
+    assert_eq!(map.eval_stat(&fire, &IntStats::A), 50);
+    assert_eq!(map.eval_stat(&QualifierQuery::none(), &IntStats::A), 15);
+
+    let mut value = StatFloat::<f32>::from_base(2.0).with_max(4.0);
+    value.rebase(&StatFloat::from_base(1.0), &StatFloat::from_base(3.5));
+    assert_eq!(value.eval(), 4.0);
+    assert!(value.is_clamped());
+}
+
+#[test]
+pub fn extend_joins() {
+    let none = Qualifier::<u32>::none();
+    let one = Qualifier::all_of(1u32);
+
+    let mut map = StatMap::new();
+    map.insert(none, IntStats::A, StatInt::from_base(2).with_mul(3));
+    map.insert_base(one, IntStats::B, 4);
+    map.extend([
+        (none, IntStats::A, StatInt::from_base(1)),
+        (one, IntStats::C, StatInt::from_base(5)),
+        (none, IntStats::A, StatInt::from_base(1).with_max(7)),
+    ]);
+    assert_eq!(map.len(), 3);
+    assert_eq!(map.get_evaled(&none, &IntStats::A), Some(7));
+    assert_eq!(
+        map.get(&none, &IntStats::A),
+        Some(&StatInt::from_base(4).with_mul(3).with_max(7))
+    );
+    assert_eq!(map.get_evaled(&one, &IntStats::B), Some(4));
+    assert_eq!(map.get_evaled(&one, &IntStats::C), Some(5));
+}
+
+stat_struct! {
+    #[derive(Debug, PartialEq)]
+    pub struct CombatStats {
+        pub attack: i32 = IntStats::A,
+        pub defense: i32 = IntStats::B,
+        pub speed: f32 = FloatStat,
+    }
+}
+
+#[test]
+pub fn stat_struct() {
+    let none = Qualifier::<u32>::none();
+    let one = Qualifier::all_of(1u32);
+
+    let mut map = StatMap::new();
+    map.insert_base(none, IntStats::A, 4);
+    map.insert_base(one, IntStats::A, 2);
+    map.modify(none, IntStats::A, Mul(3));
+    map.insert_base(none, FloatStat, 1.5);
+
+    assert_eq!(
+        CombatStats::from_stat_map(&map, &QualifierQuery::none()),
+        CombatStats {
+            attack: 12,
+            defense: 0,
+            speed: 1.5,
+        }
+    );
+    assert_eq!(
+        CombatStats::from_stat_map(&map, &QualifierQuery::Aggregate(1u32)),
+        CombatStats {
+            attack: 18,
+            defense: 0,
+            speed: 1.5,
+        }
+    );
+}
+
+#[test]
+pub fn get_evaled_or() {
+    let none = Qualifier::<u32>::none();
+    let one = Qualifier::all_of(1u32);
+
+    let mut map = StatMap::new();
+    map.insert(none, IntStats::A, StatInt::from_base(2).with_mul(3));
+    assert_eq!(map.get_evaled_or(&none, &IntStats::A, 7), 6);
+    assert_eq!(map.get_evaled_or(&one, &IntStats::A, 7), 7);
+    assert_eq!(map.get_evaled_or(&none, &IntStats::B, 7), 7);
+    assert_eq!(map.get_evaled_or_default(&none, &IntStats::A), 6);
+    assert_eq!(map.get_evaled_or_default(&none, &FloatStat), 0.0);
+}
+
+#[test]
+pub fn from_sorted_unchecked() {
+    let none = Qualifier::<u32>::none();
+    let one = Qualifier::all_of(1u32);
+    let entries = [
+        (none, IntStats::A, StatInt::from_base(1)),
+        (one, IntStats::A, StatInt::from_base(2)),
+        (none, IntStats::B, StatInt::from_base(3)),
+        (none, IntStats::C, StatInt::from_base(4).with_mul(2)),
+        (one, IntStats::C, StatInt::from_base(5)),
+    ];
+    let sorted = StatMap::from_sorted_unchecked(entries);
+    let map: StatMap<u32> = entries.into_iter().rev().collect();
+    assert_eq!(format!("{sorted:?}"), format!("{map:?}"));
+    for query in [QualifierQuery::none(), QualifierQuery::Aggregate(1)] {
+        for stat in [IntStats::A, IntStats::B, IntStats::C] {
+            assert_eq!(
+                sorted.eval_stat(&query, &stat),
+                map.eval_stat(&query, &stat)
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+pub fn from_sorted_unchecked_unsorted() {
+    let none = Qualifier::<u32>::none();
+    StatMap::from_sorted_unchecked([
+        (none, IntStats::B, StatInt::from_base(1)),
+        (none, IntStats::A, StatInt::from_base(2)),
+    ]);
+}
+
+#[test]
+pub fn retain() {
+    let none = Qualifier::<u32>::none();
+    let one = Qualifier::all_of(1u32);
+    let two = Qualifier::all_of(2u32);
+
+    let mut map = StatMap::new();
+    map.insert_base(none, IntStats::A, 1);
+    map.insert_base(one, IntStats::A, 2);
+    map.insert_base(two, IntStats::A, 3);
+    map.insert_base(none, FloatStat, 1.5);
+
+    // Remove none.
+    map.retain(|_, _| true);
+    assert_eq!(map.len(), 4);
+
+    // Remove from the middle.
+    map.retain(|qualifier, stat| !(qualifier == &one && stat.is(&IntStats::A)));
+    assert_eq!(map.len(), 3);
+    assert_eq!(map.get_evaled(&one, &IntStats::A), None);
+    assert_eq!(map.get_evaled(&none, &IntStats::A), Some(1));
+    assert_eq!(map.get_evaled(&two, &IntStats::A), Some(3));
+    assert_eq!(
+        map.eval_stat(&QualifierQuery::Aggregate(3), &IntStats::A),
+        4
+    );
+    map.insert_base(one, IntStats::A, 5);
+    assert_eq!(
+        map.eval_stat(&QualifierQuery::Aggregate(3), &IntStats::A),
+        9
+    );
+
+    map.retain_mut(&IntStats::A, |qualifier, value| {
+        value.add(10);
+        qualifier != &two
+    });
+    assert_eq!(map.len(), 3);
+    assert_eq!(map.get_evaled(&none, &IntStats::A), Some(11));
+    assert_eq!(map.get_evaled(&one, &IntStats::A), Some(15));
+    assert_eq!(map.get_evaled(&two, &IntStats::A), None);
+    assert_eq!(map.get_evaled(&none, &FloatStat), Some(1.5));
+
+    // Remove all.
+    map.retain(|_, _| false);
+    assert!(map.is_empty());
+}
+
+static LOOKUPS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Counted(u64);
+
+impl Stat for Counted {
+    type Value = StatInt<i32>;
+
+    fn name(&self) -> &'static str {
+        "Counted"
+    }
+
+    fn values() -> impl IntoIterator<Item = Self> {
+        (0..4).map(Counted)
+    }
+
+    fn vtable() -> &'static StatVTable<Self> {
+        static VTABLE: StatVTable<Counted> = StatVTable::of::<Counted>();
+        &VTABLE
+    }
+
+    fn as_index(&self) -> u64 {
+        LOOKUPS.fetch_add(1, Ordering::Relaxed);
+        self.0
+    }
+
+    fn from_index(index: u64) -> Self {
+        Counted(index)
+    }
+}
+
+#[test]
+pub fn entry() {
+    let none = Qualifier::<u32>::none();
+    let mut map = StatMap::<u32>::new();
+    map.insert_base(none, Counted(1), 1);
+    map.insert_base(none, Counted(3), 3);
+
+    LOOKUPS.store(0, Ordering::Relaxed);
+    *map.entry(none, Counted(2)).or_default() = StatInt::from_base(2);
+    assert_eq!(LOOKUPS.load(Ordering::Relaxed), 1);
+
+    LOOKUPS.store(0, Ordering::Relaxed);
+    map.entry(none, Counted(1))
+        .and_modify(|v| v.add(10))
+        .or_insert(StatInt::from_base(0));
+    assert_eq!(LOOKUPS.load(Ordering::Relaxed), 1);
+
+    LOOKUPS.store(0, Ordering::Relaxed);
+    map.entry(none, Counted(0))
+        .and_modify(|v| v.add(10))
+        .or_insert_with(|| StatInt::from_base(5));
+    assert_eq!(LOOKUPS.load(Ordering::Relaxed), 1);
+
+    assert_eq!(map.get_evaled(&none, &Counted(0)), Some(5));
+    assert_eq!(map.get_evaled(&none, &Counted(1)), Some(11));
+    assert_eq!(map.get_evaled(&none, &Counted(2)), Some(2));
+    assert_eq!(map.get_evaled(&none, &Counted(3)), Some(3));
+
+    let order: Vec<_> = map
+        .into_entries()
+        .into_iter()
+        .map(|(_, mut pair)| pair.cast::<Counted>().unwrap().0)
+        .collect();
+    assert_eq!(order, [Counted(0), Counted(1), Counted(2), Counted(3)]);
+}
+
+#[test]
+pub fn iter_all() {
+    let none = Qualifier::<u32>::none();
+    let one = Qualifier::all_of(1u32);
+    let mut map = StatMap::<u32>::new();
+    map.insert_base(none, IntStats::A, 4);
+    map.insert_base(one, FloatStat, 2.5);
+    map.insert_base(none, FlagsStat, 0b101);
+
+    let mut names: Vec<_> = map
+        .iter_all()
+        .map(|(q, name, value)| (*q, name, format!("{value:?}")))
+        .collect();
+    names.sort_by_key(|(_, name, _)| *name);
+    assert_eq!(names.len(), 3);
+    assert_eq!(names[0].0, none);
+    assert_eq!(names[0].1, "A");
+    assert!(names[0].2.contains('4'));
+    assert_eq!(names[1].0, none);
+    assert_eq!(names[1].1, "FlagsStat");
+    assert!(names[1].2.contains('5'));
+    assert_eq!(names[2].0, one);
+    assert_eq!(names[2].1, "FloatStat");
+    assert!(names[2].2.contains("2.5"));
+
+    let mut count = 0;
+    for (q, inst) in map.iter_entries() {
+        count += 1;
+        if inst.is(&FloatStat) {
+            assert_eq!(map.get_evaled(q, &FloatStat), Some(2.5));
+        } else if inst.is(&FlagsStat) {
+            assert_eq!(map.get_evaled(q, &FlagsStat), Some(0b101));
+        } else {
+            assert!(inst.is(&IntStats::A));
+            assert_eq!(map.get_evaled(q, &IntStats::A), Some(4));
+        }
+    }
+    assert_eq!(count, 3);
+}
+
+#[test]
+pub fn drain() {
+    let none = Qualifier::<u32>::none();
+    let one = Qualifier::all_of(1u32);
+    let mut source = StatMap::<u32>::new();
+    source.insert_base(none, IntStats::A, 4);
+    source.insert_base(one, IntStats::A, 7);
+    source.insert_base(none, FloatStat, 2.5);
+    source.insert_base(one, Title, "Knight".into());
+
+    let mut target = StatMap::<u32>::new();
+    target.insert_base(none, IntStats::A, 1);
+    target.insert_base(none, IntStats::B, 2);
+    for (qualifier, pair) in source.drain() {
+        target.insert_raw(qualifier, pair);
+    }
+
+    assert!(source.is_empty());
+    assert_eq!(target.len(), 5);
+    assert_eq!(target.get_evaled(&none, &IntStats::A), Some(4));
+    assert_eq!(target.get_evaled(&one, &IntStats::A), Some(7));
+    assert_eq!(target.get_evaled(&none, &IntStats::B), Some(2));
+    assert_eq!(target.get_evaled(&none, &FloatStat), Some(2.5));
+    assert_eq!(target.get_evaled(&one, &Title).as_deref(), Some("Knight"));
+
+    source.insert_base(none, Title, "Squire".into());
+    assert_eq!(source.get_evaled(&none, &Title).as_deref(), Some("Squire"));
+}
+
+#[test]
+pub fn join_from() {
+    let none = Qualifier::<u32>::none();
+    let one = Qualifier::all_of(1u32);
+    let mut a = StatMap::<u32>::new();
+    a.insert_base(none, IntStats::A, 1);
+    a.insert_base(one, IntStats::A, 2);
+    a.insert_base(none, FloatStat, 1.5);
+
+    let mut b = StatMap::<u32>::new();
+    b.insert_base(none, IntStats::A, 10);
+    b.insert_base(Qualifier::all_of(2u32), IntStats::A, 20);
+    b.insert_base(none, IntStats::C, 30);
+    b.insert_base(none, FloatStat, 2.0);
+    b.insert_base(one, Title, "Knight".into());
+
+    a.join_from(&b);
+
+    assert_eq!(a.len(), 6);
+    assert_eq!(a.get_evaled(&none, &IntStats::A), Some(11));
+    assert_eq!(a.get_evaled(&one, &IntStats::A), Some(2));
+    assert_eq!(
+        a.get_evaled(&Qualifier::all_of(2u32), &IntStats::A),
+        Some(20)
+    );
+    assert_eq!(a.get_evaled(&none, &IntStats::C), Some(30));
+    assert_eq!(a.get_evaled(&none, &FloatStat), Some(3.5));
+    assert_eq!(a.get_evaled(&one, &Title).as_deref(), Some("Knight"));
+    assert_eq!(b.len(), 5);
+    assert_eq!(b.get_evaled(&none, &IntStats::A), Some(10));
+
+    let keys: Vec<_> = a.iter_entries().map(|(q, s)| (s, *q)).collect();
+    let mut sorted = keys.clone();
+    sorted.sort();
+    assert_eq!(keys, sorted);
+}
+
+#[test]
+pub fn capacity() {
+    let none = Qualifier::<u32>::none();
+    let mut map = StatMap::<u32>::with_capacity(8);
+    assert!(map.capacity() >= 8);
+    let capacity = map.capacity();
+    for i in 0..8 {
+        map.insert_base(Qualifier::all_of(1 << i), IntStats::A, i);
+    }
+    assert_eq!(map.capacity(), capacity);
+
+    map.reserve(16);
+    assert!(map.capacity() >= 24);
+    map.remove_all(&IntStats::A);
+    map.insert_base(none, IntStats::B, 1);
+    map.shrink_to_fit();
+    assert!(map.capacity() < 24);
+
+    let map: StatMap<u32> = (0..4)
+        .map(|i| {
+            (
+                Qualifier::all_of(1 << i),
+                IntStats::C,
+                StatInt::from_base(i),
+            )
+        })
+        .collect();
+    assert_eq!(map.len(), 4);
+    assert!(map.capacity() >= 4);
+}
+
+#[test]
+pub fn get_base() {
+    let none = Qualifier::<u32>::none();
+    let mut map = StatMap::<u32>::new();
+    map.insert_base(none, IntStats::A, 4);
+    map.insert_base(none, FloatStat, 2.5);
+    map.insert_base(none, FlagsStat, 0b101);
+    map.insert_base(none, Title, "Knight".into());
+
+    assert_eq!(map.get_base(&none, &IntStats::A), Some(4));
+    assert_eq!(map.get_base(&none, &FloatStat), Some(2.5));
+    assert_eq!(map.get_base(&none, &FlagsStat), Some(0b101));
+    assert_eq!(map.get_base(&none, &Title), Some("Knight".into()));
+    assert_eq!(map.get_base(&none, &IntStats::B), None);
+}
+
+#[test]
+pub fn contains_count() {
+    let none = Qualifier::<u32>::none();
+    let one = Qualifier::all_of(1u32);
+    let two = Qualifier::all_of(2u32);
+    let mut map = StatMap::<u32>::new();
+    map.insert_base(none, IntStats::A, 1);
+    map.insert_base(one, IntStats::A, 2);
+    map.insert_base(two, IntStats::A, 3);
+    map.insert_base(one, IntStats::B, 4);
+    map.insert_base(none, FloatStat, 1.0);
+
+    assert!(map.contains(&none, &IntStats::A));
+    assert!(map.contains(&two, &IntStats::A));
+    assert!(map.contains(&one, &IntStats::B));
+    assert!(!map.contains(&none, &IntStats::B));
+    assert!(!map.contains(&one, &FloatStat));
+    assert!(!map.contains(&none, &IntStats::C));
+
+    assert_eq!(map.count(&IntStats::A), 3);
+    assert_eq!(map.count(&IntStats::B), 1);
+    assert_eq!(map.count(&IntStats::C), 0);
+    assert_eq!(map.count(&FloatStat), 1);
+    assert_eq!(map.count(&FlagsStat), 0);
+}
+
+#[test]
+pub fn stable_serialization_order() {
+    let none = Qualifier::<u32>::none();
+    let one = Qualifier::all_of(1u32);
+    let mut a = StatMap::<u32>::new();
+    a.insert_base(one, IntStats::C, 3);
+    a.insert_base(none, FloatStat, 1.5);
+    a.insert_base(none, IntStats::C, 2);
+    a.insert_base(none, FlagsStat, 4);
+    a.insert_base(none, IntStats::A, 1);
+
+    let mut b = StatMap::<u32>::new();
+    b.insert_base(none, IntStats::A, 1);
+    b.insert_base(none, FlagsStat, 4);
+    b.insert_base(none, IntStats::C, 2);
+    b.insert_base(none, FloatStat, 1.5);
+    b.insert_base(one, IntStats::C, 3);
+
+    let json = serde_json::to_string(&a).unwrap();
+    assert_eq!(json, serde_json::to_string(&b).unwrap());
+
+    let value = serde_json::to_value(&a).unwrap();
+    let names: Vec<_> = value["inner"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry[1].as_str().unwrap())
+        .collect();
+    assert_eq!(names, ["A", "C", "C", "FlagsStat", "FloatStat"]);
+
+    let mut versioned = Vec::new();
+    a.serialize_versioned(&mut serde_json::Serializer::new(&mut versioned))
+        .unwrap();
+    let mut versioned_b = Vec::new();
+    b.serialize_versioned(&mut serde_json::Serializer::new(&mut versioned_b))
+        .unwrap();
+    assert_eq!(versioned, versioned_b);
+}
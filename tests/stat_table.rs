@@ -0,0 +1,96 @@
+#![cfg(feature = "asset")]
+use bevy::{
+    asset::{AssetApp, AssetPlugin, AssetServer, Assets, Handle, LoadState},
+    core::TaskPoolPlugin,
+    prelude::{ResMut, Single},
+};
+use bevy_app::App;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    query::With,
+    system::{Commands, RunSystemOnce},
+};
+use bevy_stat_query::{
+    operations::StatOperation::Mul, types::StatFloat, Qualifier, QualifierQuery, Stat,
+    StatEntities, StatEntity, StatExtension, StatMap, StatQuery, StatTable, StatTableAsset,
+    StatTableLoader,
+};
+
+#[derive(Debug, Clone, Copy, Stat)]
+#[stat(value = "StatFloat<f32>")]
+pub enum Stats {
+    Damage,
+    Defense,
+}
+
+#[derive(Component)]
+pub struct A;
+
+#[test]
+pub fn stat_table_test() {
+    let mut app = App::new();
+    app.add_plugins(AssetPlugin::default())
+        .init_asset::<StatTableAsset<u32>>();
+    app.world_mut().run_system_once(init_table).unwrap();
+    app.world_mut().flush();
+    app.world_mut().run_system_once(query_table).unwrap();
+}
+
+#[test]
+pub fn stat_table_loader_test() {
+    let mut app = App::new();
+    app.add_plugins((
+        TaskPoolPlugin::default(),
+        AssetPlugin {
+            file_path: "tests/assets".into(),
+            ..Default::default()
+        },
+    ))
+    .register_stat::<Stats>()
+    .init_asset::<StatTableAsset<u32>>()
+    .init_asset_loader::<StatTableLoader<u32>>();
+
+    let handle: Handle<StatTableAsset<u32>> = app
+        .world()
+        .resource::<AssetServer>()
+        .load("table.stats.ron");
+    for _ in 0..1000 {
+        app.update();
+        match app.world().resource::<AssetServer>().load_state(&handle) {
+            LoadState::Loaded => break,
+            LoadState::Failed(err) => panic!("{err}"),
+            _ => std::thread::sleep(std::time::Duration::from_millis(1)),
+        }
+    }
+    app.world_mut().spawn((StatEntity, A, StatTable(handle)));
+    app.world_mut().run_system_once(query_table).unwrap();
+}
+
+fn init_table(mut commands: Commands, mut assets: ResMut<Assets<StatTableAsset<u32>>>) {
+    let mut map = StatMap::new();
+    map.insert_base(Qualifier::none(), Stats::Damage, 4.0);
+    map.modify(Qualifier::none(), Stats::Damage, Mul(2.0));
+    map.insert_base(Qualifier::all_of(1), Stats::Defense, 3.0);
+    commands.spawn((StatEntity, A, StatTable(assets.add(map))));
+}
+
+fn query_table(
+    querier: StatEntities<u32>,
+    table_query: StatQuery<StatTable<u32>>,
+    a: Single<Entity, (With<StatEntity>, With<A>)>,
+) {
+    let querier = querier.join(&table_query);
+    assert_eq!(
+        querier.eval_stat(*a, &QualifierQuery::Aggregate(0u32), &Stats::Damage),
+        Some(8.0)
+    );
+    assert_eq!(
+        querier.eval_stat(*a, &QualifierQuery::Aggregate(0u32), &Stats::Defense),
+        Some(0.0)
+    );
+    assert_eq!(
+        querier.eval_stat(*a, &QualifierQuery::Aggregate(1u32), &Stats::Defense),
+        Some(3.0)
+    );
+}